@@ -2,17 +2,15 @@
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{BankMsg, Coin, DepsMut, Env, MessageInfo, Reply, Response, SubMsg, Uint128};
 use cw2::set_contract_version;
-use protobuf::Message;
-use std::str::FromStr;
 
 use serde::{Deserialize, Serialize};
 
 use injective_cosmwasm::{
-    create_spot_market_order_msg, get_default_subaccount_id_for_checked_address,
-    InjectiveMsgWrapper, InjectiveQuerier, InjectiveQueryWrapper, OrderType, SpotOrder,
+    create_spot_market_order_msg, get_default_subaccount_id_for_checked_address, ExchangeReply,
+    InjectiveMsgWrapper, InjectiveQuerier, InjectiveQueryWrapper, MsgCreateSpotMarketOrderResponse,
+    OrderType, SpotOrder,
 };
 use injective_math::FPDecimal;
-use injective_protobuf::proto::tx;
 
 use crate::error::ContractError;
 use crate::msg::{ExecuteMsg, InstantiateMsg};
@@ -147,34 +145,34 @@ fn handle_atomic_order_reply(
     _env: Env,
     msg: Reply,
 ) -> Result<Response<InjectiveMsgWrapper>, ContractError> {
-    let dec_scale_factor: FPDecimal = FPDecimal::from(1000000000000000000_i128);
     let id = msg.id;
-    let order_response: tx::MsgCreateSpotMarketOrderResponse = Message::parse_from_bytes(
-        msg.result
-            .into_result()
-            .map_err(ContractError::SubMsgFailure)?
-            .data
-            .ok_or_else(|| ContractError::ReplyParseFailure {
+    let data = msg
+        .result
+        .into_result()
+        .map_err(ContractError::SubMsgFailure)?
+        .data
+        .ok_or_else(|| ContractError::ReplyParseFailure {
+            id,
+            err: "Missing reply data".to_owned(),
+        })?;
+    let order_response =
+        MsgCreateSpotMarketOrderResponse::decode(data.as_slice()).map_err(|err| {
+            ContractError::ReplyParseFailure {
                 id,
-                err: "Missing reply data".to_owned(),
-            })?
-            .as_slice(),
-    )
-    .map_err(|err| ContractError::ReplyParseFailure {
-        id,
-        err: err.to_string(),
-    })?;
+                err: err.to_string(),
+            }
+        })?;
 
     // unwrap results into trade_data
-    let trade_data = match order_response.results.into_option() {
+    let trade_data = match order_response.results {
         Some(trade_data) => Ok(trade_data),
         None => Err(ContractError::CustomError {
             val: "No trade data in order response".to_string(),
         }),
     }?;
-    let quantity = FPDecimal::from_str(&trade_data.quantity)? / dec_scale_factor;
-    let price = FPDecimal::from_str(&trade_data.price)? / dec_scale_factor;
-    let fee = FPDecimal::from_str(&trade_data.fee)? / dec_scale_factor;
+    let quantity = trade_data.quantity;
+    let price = trade_data.price;
+    let fee = trade_data.fee;
 
     let config = STATE.load(deps.storage)?;
 
@@ -6,11 +6,11 @@ use cosmwasm_std::{
 use cw2::set_contract_version;
 use cw_storage_plus::Item;
 
-use injective_cosmwasm::{InjectiveMsgWrapper, InjectiveQueryWrapper};
+use injective_cosmwasm::{InjectiveMsgWrapper, InjectiveQueryWrapper, WasmxSudoMsg};
 
 use crate::error::ContractError;
 use crate::mock_pyth_attestation::execute_trigger_pyth_update;
-use crate::msg::{ExecuteMsg, InstantiateMsg, QueryMsg, SudoMsg};
+use crate::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
 
 // version info for migration info
 const CONTRACT_NAME: &str = "crates.io:injective:dummy";
@@ -53,15 +53,15 @@ pub fn execute(
 }
 
 #[entry_point]
-pub fn sudo(deps: DepsMut, _env: Env, msg: SudoMsg) -> Result<Response, ContractError> {
+pub fn sudo(deps: DepsMut, _env: Env, msg: WasmxSudoMsg) -> Result<Response, ContractError> {
     match msg {
-        SudoMsg::BeginBlocker {} => {
+        WasmxSudoMsg::BeginBlocker {} => {
             let runs = COUNTER.load(deps.storage)? + 1;
             COUNTER.save(deps.storage, &runs)?;
             ACTIVE.save(deps.storage, &true)?;
             Ok(Response::new())
         }
-        SudoMsg::Deregister {} | SudoMsg::Deactivate {} => {
+        WasmxSudoMsg::Deregister {} | WasmxSudoMsg::Deactivate {} => {
             ACTIVE.save(deps.storage, &false)?;
             Ok(Response::new())
         }
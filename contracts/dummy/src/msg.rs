@@ -12,14 +12,6 @@ pub enum ExecuteMsg {
     TriggerPythUpdate { price: i64 },
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
-#[serde(rename_all = "snake_case")]
-pub enum SudoMsg {
-    BeginBlocker {},
-    Deactivate {},
-    Deregister {},
-}
-
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum QueryMsg {
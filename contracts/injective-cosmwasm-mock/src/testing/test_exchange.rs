@@ -10,9 +10,9 @@ use cosmwasm_std::{Addr, Coin};
 use injective_cosmwasm::{
     checked_address_to_subaccount_id,
     exchange::{response::QueryOrderbookResponse, types::VolumeByType},
-    CancellationStrategy, ExchangeParamsResponse, MarketId, MarketMidPriceAndTOBResponse, MarketVolatilityResponse, OrderSide, PriceLevel,
-    QueryAggregateVolumeResponse, QueryMarketAtomicExecutionFeeMultiplierResponse, SpotMarketResponse, SubaccountDepositResponse, SubaccountId,
-    TraderSpotOrdersResponse, TrimmedSpotLimitOrder,
+    CancellationStrategy, ExchangeParamsResponse, MarketId, MarketMidPriceAndTOBResponse, MarketVolatilityResponse, OrderHashGenerator, OrderSide,
+    PriceLevel, QueryAggregateVolumeResponse, QueryMarketAtomicExecutionFeeMultiplierResponse, SpotMarketResponse, SubaccountDepositResponse,
+    SubaccountId, TraderSpotOrdersResponse, TrimmedSpotLimitOrder,
 };
 use injective_math::FPDecimal;
 use injective_std::types::injective::exchange::v1beta1::{
@@ -521,3 +521,53 @@ fn test_query_trader_transient_spot_orders() {
     let expected_order_info = "TraderSpotOrdersResponse { orders: Some([TrimmedSpotLimitOrder { price: FPDecimal { num: 9800000, sign: 1 }, quantity: FPDecimal { num: 1000000000000000000000000000000000000, sign: 1 }";
     assert!(transient_query.unwrap().value.contains(expected_order_info));
 }
+
+#[test]
+#[cfg_attr(not(feature = "integration"), ignore)]
+fn test_order_hashes_match_the_chain() {
+    use injective_std::types::injective::exchange::v1beta1::{MsgCreateSpotLimitOrder, OrderInfo, SpotOrder};
+
+    let env = Setup::new(ExchangeType::Spot);
+    let exchange = Exchange::new(&env.app);
+    let market_id = env.market_id.unwrap();
+    let trader = &env.users[0];
+
+    // fresh accounts start at trade nonce 0
+    let mut generator = OrderHashGenerator::new(Addr::unchecked(trader.account.address()), 0);
+    let (price, quantity) = scale_price_quantity_for_spot_market_dec("9.8", "1", &BASE_DECIMALS, &QUOTE_DECIMALS);
+
+    for fee_recipient in [Some(trader.account.address()), None] {
+        let order_hash = exchange
+            .create_spot_limit_order(
+                MsgCreateSpotLimitOrder {
+                    sender: trader.account.address(),
+                    order: Some(SpotOrder {
+                        market_id: market_id.to_owned(),
+                        order_info: Some(OrderInfo {
+                            subaccount_id: trader.subaccount_id.to_string(),
+                            fee_recipient: fee_recipient.clone().unwrap_or_default(),
+                            price: dec_to_proto(price),
+                            quantity: dec_to_proto(quantity),
+                        }),
+                        order_type: OrderType::Buy.into(),
+                        trigger_price: "".to_string(),
+                    }),
+                },
+                &trader.account,
+            )
+            .unwrap()
+            .data
+            .order_hash;
+
+        let order = injective_cosmwasm::SpotOrder::new(
+            price,
+            quantity,
+            injective_cosmwasm::OrderType::Buy,
+            &MarketId::unchecked(&market_id),
+            trader.subaccount_id.clone(),
+            fee_recipient.map(Addr::unchecked),
+            None,
+        );
+        assert_eq!(generator.next_spot_order_hash(&order).unwrap().to_string(), order_hash);
+    }
+}
@@ -9,7 +9,7 @@ use crate::{
 use cosmwasm_std::Addr;
 use injective_cosmwasm::{
     checked_address_to_subaccount_id, exchange::response::QueryOrderbookResponse, CancellationStrategy, DerivativeMarketResponse, MarketId,
-    MarketMidPriceAndTOBResponse, PerpetualMarketFundingResponse, PerpetualMarketInfoResponse, PriceLevel,
+    MarketMidPriceAndTOBResponse, OrderHashGenerator, PerpetualMarketFundingResponse, PerpetualMarketInfoResponse, PriceLevel,
     SubaccountEffectivePositionInMarketResponse, SubaccountId, SubaccountPositionInMarketResponse, TraderDerivativeOrdersResponse,
     TrimmedDerivativeLimitOrder,
 };
@@ -394,3 +394,55 @@ fn test_query_trader_transient_derivative_orders() {
     let expected_order_info = "Ok(TraderDerivativeOrdersResponse { orders: Some([TrimmedDerivativeLimitOrder { price: FPDecimal { num: 9700000000000000000000000, sign: 1 }, quantity: FPDecimal { num: 100000000000000000, sign: 1 }, margin: FPDecimal { num: 1940000000000000000000000, sign: 1 }, fillable: FPDecimal { num: 100000000000000000, sign: 1 }, isBuy: true";
     assert!(transient_query.unwrap().value.contains(expected_order_info));
 }
+
+#[test]
+#[cfg_attr(not(feature = "integration"), ignore)]
+fn test_order_hashes_match_the_chain() {
+    use injective_std::types::injective::exchange::v1beta1::{DerivativeOrder, MsgCreateDerivativeLimitOrder, OrderInfo};
+
+    let env = Setup::new(ExchangeType::Derivative);
+    let exchange = Exchange::new(&env.app);
+    let market_id = env.market_id.unwrap();
+    let trader = &env.users[0];
+
+    // fresh accounts start at trade nonce 0
+    let mut generator = OrderHashGenerator::new(Addr::unchecked(trader.account.address()), 0);
+    let (price, quantity, margin) = scale_price_quantity_perp_market_dec("9.8", "1", "2", &QUOTE_DECIMALS);
+
+    for fee_recipient in [Some(trader.account.address()), None] {
+        let order_hash = exchange
+            .create_derivative_limit_order(
+                MsgCreateDerivativeLimitOrder {
+                    sender: trader.account.address(),
+                    order: Some(DerivativeOrder {
+                        market_id: market_id.to_owned(),
+                        order_info: Some(OrderInfo {
+                            subaccount_id: trader.subaccount_id.to_string(),
+                            fee_recipient: fee_recipient.clone().unwrap_or_default(),
+                            price: dec_to_proto(price),
+                            quantity: dec_to_proto(quantity),
+                        }),
+                        margin: dec_to_proto(margin),
+                        order_type: OrderType::Buy.into(),
+                        trigger_price: "".to_string(),
+                    }),
+                },
+                &trader.account,
+            )
+            .unwrap()
+            .data
+            .order_hash;
+
+        let order = injective_cosmwasm::DerivativeOrder::new(
+            price,
+            quantity,
+            margin,
+            injective_cosmwasm::OrderType::Buy,
+            MarketId::unchecked(&market_id),
+            trader.subaccount_id.clone(),
+            fee_recipient.map(Addr::unchecked),
+            None,
+        );
+        assert_eq!(generator.next_derivative_order_hash(&order).unwrap().to_string(), order_hash);
+    }
+}
@@ -0,0 +1,3 @@
+pub mod response;
+pub mod types;
+pub mod valuation;
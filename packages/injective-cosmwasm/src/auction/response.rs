@@ -0,0 +1,72 @@
+use cosmwasm_std::{Coin, Timestamp, Uint128};
+use injective_math::{utils::round_up_to_min_tick, FPDecimal};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::auction::types::AuctionParams;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct AuctionParamsResponse {
+    pub params: AuctionParams,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct CurrentAuctionBasketResponse {
+    /// Coins auctioned off in the current round
+    #[serde(default)]
+    pub amount: Vec<Coin>,
+    pub auction_round: u64,
+    /// Unix timestamp in seconds at which the round closes
+    pub auction_closing_time: i64,
+    /// Empty when no bid has been placed in this round
+    #[serde(default)]
+    pub highest_bidder: String,
+    /// Amount of INJ bid by the highest bidder
+    #[serde(default)]
+    pub highest_bid_amount: Uint128,
+}
+
+impl CurrentAuctionBasketResponse {
+    pub fn has_bid(&self) -> bool {
+        !self.highest_bidder.is_empty()
+    }
+
+    pub fn is_closed(&self, now: Timestamp) -> bool {
+        now.seconds() as i64 >= self.auction_closing_time
+    }
+
+    /// Smallest INJ amount accepted as the next bid
+    pub fn min_next_bid(&self, params: &AuctionParams) -> Uint128 {
+        let highest_bid = FPDecimal::from(self.highest_bid_amount);
+        let min_bid = highest_bid * (FPDecimal::ONE + params.min_next_bid_increment_rate);
+        Uint128::from(u128::from(round_up_to_min_tick(min_bid, FPDecimal::ONE)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{Timestamp, Uint128};
+    use injective_math::FPDecimal;
+
+    use crate::auction::{response::CurrentAuctionBasketResponse, types::AuctionParams};
+
+    #[test]
+    fn min_next_bid_adds_increment_to_highest_bid() {
+        let basket = CurrentAuctionBasketResponse {
+            amount: vec![],
+            auction_round: 42,
+            auction_closing_time: 1_700_000_000,
+            highest_bidder: "inj1cml96vmptgw99syqrrz8az79xer2pcgp0a885r".to_string(),
+            highest_bid_amount: Uint128::new(1_000_001),
+        };
+        let params = AuctionParams {
+            auction_period: 604_800,
+            min_next_bid_increment_rate: FPDecimal::must_from_str("0.0025"),
+        };
+
+        assert!(basket.has_bid());
+        assert_eq!(basket.min_next_bid(&params), Uint128::new(1_002_502));
+        assert!(!basket.is_closed(Timestamp::from_seconds(1_699_999_999)));
+        assert!(basket.is_closed(Timestamp::from_seconds(1_700_000_000)));
+    }
+}
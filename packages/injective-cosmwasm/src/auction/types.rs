@@ -0,0 +1,11 @@
+use injective_math::FPDecimal;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct AuctionParams {
+    /// Duration of an auction round in seconds
+    pub auction_period: i64,
+    /// A new bid must exceed the highest bid by at least this rate
+    pub min_next_bid_increment_rate: FPDecimal,
+}
@@ -0,0 +1,129 @@
+use cosmwasm_std::{Coin, StdResult};
+use injective_math::{scale::Scaled, FPDecimal};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::oracle::types::OracleType;
+use crate::querier::InjectiveQuerier;
+
+/// Oracle pair pricing a basket denom in the quote asset
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct BasketPriceSource {
+    pub denom: String,
+    pub decimals: u32,
+    pub oracle_type: OracleType,
+    pub oracle_base: String,
+    pub oracle_quote: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct ValuedCoin {
+    pub coin: Coin,
+    pub price: FPDecimal,
+    /// Human amount of the coin times its price
+    pub value: FPDecimal,
+}
+
+/// Value of an auction basket in human units of the quote asset
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct BasketValuation {
+    pub priced: Vec<ValuedCoin>,
+    /// Coins without a price source or without an oracle price, excluded from the total
+    pub unpriced: Vec<Coin>,
+    pub total_value: FPDecimal,
+}
+
+impl BasketValuation {
+    /// Queries the oracle price of every basket coin that has a source in `price_sources`
+    pub fn query(querier: &InjectiveQuerier, basket: &[Coin], price_sources: &[BasketPriceSource]) -> StdResult<Self> {
+        let mut priced = vec![];
+        let mut unpriced = vec![];
+
+        for coin in basket {
+            let source = match price_sources.iter().find(|source| source.denom == coin.denom) {
+                Some(source) => source,
+                None => {
+                    unpriced.push(coin.to_owned());
+                    continue;
+                }
+            };
+
+            let price_pair_state = querier
+                .query_oracle_price(&source.oracle_type, &source.oracle_base, &source.oracle_quote)?
+                .price_pair_state;
+            match price_pair_state {
+                Some(state) => {
+                    let amount = FPDecimal::from(coin.amount).scaled(-(source.decimals as i32));
+                    priced.push(ValuedCoin {
+                        coin: coin.to_owned(),
+                        price: state.pair_price,
+                        value: amount * state.pair_price,
+                    });
+                }
+                None => unpriced.push(coin.to_owned()),
+            }
+        }
+
+        let total_value = priced.iter().fold(FPDecimal::ZERO, |total, coin| total + coin.value);
+        Ok(BasketValuation {
+            priced,
+            unpriced,
+            total_value,
+        })
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.unpriced.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{Coin, Uint128};
+    use injective_math::FPDecimal;
+
+    use crate::auction::valuation::{BasketPriceSource, BasketValuation};
+    use crate::exchange_mock_querier::{handlers, mock_dependencies};
+    use crate::oracle::types::OracleType;
+    use crate::querier::InjectiveQuerier;
+
+    fn source(denom: &str, decimals: u32) -> BasketPriceSource {
+        BasketPriceSource {
+            denom: denom.to_string(),
+            decimals,
+            oracle_type: OracleType::PriceFeed,
+            oracle_base: denom.to_uppercase(),
+            oracle_quote: "USDT".to_string(),
+        }
+    }
+
+    #[test]
+    fn basket_coins_are_valued_in_human_units() {
+        let mut deps = mock_dependencies();
+        deps.querier.oracle_price_response_handler = handlers::create_oracle_query_handler(
+            FPDecimal::must_from_str("2.5"),
+            FPDecimal::must_from_str("2.5"),
+            FPDecimal::ONE,
+            FPDecimal::ZERO,
+            FPDecimal::ZERO,
+            0,
+            0,
+        );
+
+        let basket = vec![
+            Coin::new(3_000_000u128, "atom"),
+            Coin::new(2_000_000_000_000_000_000u128, "weth"),
+            Coin::new(10u128, "factory/inj1cml96vmptgw99syqrrz8az79xer2pcgp0a885r/lp"),
+        ];
+
+        let deps = deps.as_ref();
+        let querier = InjectiveQuerier::new(&deps.querier);
+        let valuation = BasketValuation::query(&querier, &basket, &[source("atom", 6), source("weth", 18)]).unwrap();
+
+        assert_eq!(valuation.priced.len(), 2);
+        assert_eq!(valuation.priced[0].value, FPDecimal::must_from_str("7.5"));
+        assert_eq!(valuation.total_value, FPDecimal::must_from_str("12.5"));
+        assert_eq!(valuation.unpriced[0].amount, Uint128::new(10));
+        assert!(!valuation.is_complete());
+    }
+}
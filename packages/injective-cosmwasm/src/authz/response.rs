@@ -1,11 +1,22 @@
+use cosmwasm_std::{Binary, Timestamp};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::authz::types::Authorization;
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub struct Grant {
-    authorization: String,
-    expiration: u64,
+    pub authorization: Authorization,
+    /// Unix timestamp in seconds, zero if the grant never expires
+    #[serde(default)]
+    pub expiration: u64,
+}
+
+impl Grant {
+    pub fn is_expired(&self, now: Timestamp) -> bool {
+        self.expiration != 0 && now.seconds() >= self.expiration
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
@@ -13,14 +24,15 @@ pub struct Grant {
 pub struct GrantAuthorization {
     pub granter: String,
     pub grantee: String,
-    pub authorization: String,
+    pub authorization: Authorization,
+    #[serde(default)]
     pub expiration: u64,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub struct PageResponse {
-    pub next_key: Option<Vec<u8>>,
+    pub next_key: Option<Binary>,
     pub total: Option<u64>,
 }
 
@@ -30,6 +42,13 @@ pub struct GrantsResponse {
     pub pagination: Option<PageResponse>,
 }
 
+impl GrantsResponse {
+    /// Whether any of the returned grants is still valid at `now`
+    pub fn has_active_grant(&self, now: Timestamp) -> bool {
+        self.grants.iter().flatten().any(|grant| !grant.is_expired(now))
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 pub struct GranteeGrantsResponse {
     pub grants: Option<Vec<GrantAuthorization>>,
@@ -0,0 +1,181 @@
+use cosmwasm_std::Binary;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::exchange::types::{MarketId, SubaccountId};
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct PageRequest {
+    pub key: Option<Binary>,
+    #[serde(default)]
+    pub offset: u64,
+    #[serde(default)]
+    pub limit: u64,
+    #[serde(default)]
+    pub count_total: bool,
+    #[serde(default)]
+    pub reverse: bool,
+}
+
+impl PageRequest {
+    pub fn with_limit(limit: u64) -> Self {
+        PageRequest { limit, ..Default::default() }
+    }
+
+    pub fn with_key(key: Binary, limit: u64) -> Self {
+        PageRequest {
+            key: Some(key),
+            limit,
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct MarketsAuthz {
+    pub subaccount_id: SubaccountId,
+    #[serde(default)]
+    pub market_ids: Vec<MarketId>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct BatchUpdateOrdersAuthz {
+    pub subaccount_id: SubaccountId,
+    #[serde(default)]
+    pub spot_markets: Vec<MarketId>,
+    #[serde(default)]
+    pub derivative_markets: Vec<MarketId>,
+}
+
+/// Authorization attached to a grant, discriminated by its protobuf type url
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(tag = "@type")]
+pub enum Authorization {
+    #[serde(rename = "/cosmos.authz.v1beta1.GenericAuthorization")]
+    Generic { msg: String },
+    #[serde(rename = "/injective.exchange.v1beta1.CreateSpotLimitOrderAuthz")]
+    CreateSpotLimitOrder(MarketsAuthz),
+    #[serde(rename = "/injective.exchange.v1beta1.CreateSpotMarketOrderAuthz")]
+    CreateSpotMarketOrder(MarketsAuthz),
+    #[serde(rename = "/injective.exchange.v1beta1.BatchCreateSpotLimitOrdersAuthz")]
+    BatchCreateSpotLimitOrders(MarketsAuthz),
+    #[serde(rename = "/injective.exchange.v1beta1.CancelSpotOrderAuthz")]
+    CancelSpotOrder(MarketsAuthz),
+    #[serde(rename = "/injective.exchange.v1beta1.BatchCancelSpotOrdersAuthz")]
+    BatchCancelSpotOrders(MarketsAuthz),
+    #[serde(rename = "/injective.exchange.v1beta1.CreateDerivativeLimitOrderAuthz")]
+    CreateDerivativeLimitOrder(MarketsAuthz),
+    #[serde(rename = "/injective.exchange.v1beta1.CreateDerivativeMarketOrderAuthz")]
+    CreateDerivativeMarketOrder(MarketsAuthz),
+    #[serde(rename = "/injective.exchange.v1beta1.BatchCreateDerivativeLimitOrdersAuthz")]
+    BatchCreateDerivativeLimitOrders(MarketsAuthz),
+    #[serde(rename = "/injective.exchange.v1beta1.CancelDerivativeOrderAuthz")]
+    CancelDerivativeOrder(MarketsAuthz),
+    #[serde(rename = "/injective.exchange.v1beta1.BatchCancelDerivativeOrdersAuthz")]
+    BatchCancelDerivativeOrders(MarketsAuthz),
+    #[serde(rename = "/injective.exchange.v1beta1.BatchUpdateOrdersAuthz")]
+    BatchUpdateOrders(BatchUpdateOrdersAuthz),
+    /// Any authorization type not modelled above, e.g. bank SendAuthorization
+    #[serde(other)]
+    Unknown,
+}
+
+impl Authorization {
+    pub fn generic(msg_type_url: impl Into<String>) -> Self {
+        Authorization::Generic { msg: msg_type_url.into() }
+    }
+
+    pub fn is_exchange(&self) -> bool {
+        !matches!(self, Authorization::Generic { .. } | Authorization::Unknown)
+    }
+
+    /// Type url of the message this authorization allows the grantee to execute
+    pub fn msg_type_url(&self) -> Option<&str> {
+        let msg_type_url = match self {
+            Authorization::Generic { msg } => msg.as_str(),
+            Authorization::CreateSpotLimitOrder(_) => "/injective.exchange.v1beta1.MsgCreateSpotLimitOrder",
+            Authorization::CreateSpotMarketOrder(_) => "/injective.exchange.v1beta1.MsgCreateSpotMarketOrder",
+            Authorization::BatchCreateSpotLimitOrders(_) => "/injective.exchange.v1beta1.MsgBatchCreateSpotLimitOrders",
+            Authorization::CancelSpotOrder(_) => "/injective.exchange.v1beta1.MsgCancelSpotOrder",
+            Authorization::BatchCancelSpotOrders(_) => "/injective.exchange.v1beta1.MsgBatchCancelSpotOrders",
+            Authorization::CreateDerivativeLimitOrder(_) => "/injective.exchange.v1beta1.MsgCreateDerivativeLimitOrder",
+            Authorization::CreateDerivativeMarketOrder(_) => "/injective.exchange.v1beta1.MsgCreateDerivativeMarketOrder",
+            Authorization::BatchCreateDerivativeLimitOrders(_) => "/injective.exchange.v1beta1.MsgBatchCreateDerivativeLimitOrders",
+            Authorization::CancelDerivativeOrder(_) => "/injective.exchange.v1beta1.MsgCancelDerivativeOrder",
+            Authorization::BatchCancelDerivativeOrders(_) => "/injective.exchange.v1beta1.MsgBatchCancelDerivativeOrders",
+            Authorization::BatchUpdateOrders(_) => "/injective.exchange.v1beta1.MsgBatchUpdateOrders",
+            Authorization::Unknown => return None,
+        };
+
+        Some(msg_type_url)
+    }
+
+    /// Whether the authorization covers the given market, generic authorizations are not restricted to markets
+    pub fn allows_market(&self, market_id: &MarketId) -> bool {
+        match self {
+            Authorization::Generic { .. } => true,
+            Authorization::Unknown => false,
+            Authorization::BatchUpdateOrders(authz) => authz.spot_markets.contains(market_id) || authz.derivative_markets.contains(market_id),
+            Authorization::CreateSpotLimitOrder(authz)
+            | Authorization::CreateSpotMarketOrder(authz)
+            | Authorization::BatchCreateSpotLimitOrders(authz)
+            | Authorization::CancelSpotOrder(authz)
+            | Authorization::BatchCancelSpotOrders(authz)
+            | Authorization::CreateDerivativeLimitOrder(authz)
+            | Authorization::CreateDerivativeMarketOrder(authz)
+            | Authorization::BatchCreateDerivativeLimitOrders(authz)
+            | Authorization::CancelDerivativeOrder(authz)
+            | Authorization::BatchCancelDerivativeOrders(authz) => authz.market_ids.contains(market_id),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::authz::types::{Authorization, MarketsAuthz};
+    use crate::{MarketId, SubaccountId};
+
+    const SUBACCOUNT_ID: &str = "0x427aee334987c52fa7b567b2662bdbb68614e48c000000000000000000000001";
+    const MARKET_ID: &str = "0x01edfab47f124748dc89998eb33144af734484ba07099014594321729a0ca16b";
+
+    #[test]
+    fn deserialize_generic_authorization() {
+        let json = r#"{"@type":"/cosmos.authz.v1beta1.GenericAuthorization","msg":"/injective.exchange.v1beta1.MsgBatchUpdateOrders"}"#;
+        let authorization: Authorization = serde_json_wasm::from_str(json).unwrap();
+
+        assert!(!authorization.is_exchange());
+        assert_eq!(authorization, Authorization::generic("/injective.exchange.v1beta1.MsgBatchUpdateOrders"));
+        assert_eq!(authorization.msg_type_url(), Some("/injective.exchange.v1beta1.MsgBatchUpdateOrders"));
+    }
+
+    #[test]
+    fn deserialize_exchange_authorization() {
+        let json = format!(
+            r#"{{"@type":"/injective.exchange.v1beta1.CreateSpotLimitOrderAuthz","subaccount_id":"{SUBACCOUNT_ID}","market_ids":["{MARKET_ID}"]}}"#
+        );
+        let authorization: Authorization = serde_json_wasm::from_str(&json).unwrap();
+
+        assert_eq!(
+            authorization,
+            Authorization::CreateSpotLimitOrder(MarketsAuthz {
+                subaccount_id: SubaccountId::unchecked(SUBACCOUNT_ID),
+                market_ids: vec![MarketId::unchecked(MARKET_ID)],
+            })
+        );
+        assert!(authorization.is_exchange());
+        assert!(authorization.allows_market(&MarketId::unchecked(MARKET_ID)));
+        assert_eq!(authorization.msg_type_url(), Some("/injective.exchange.v1beta1.MsgCreateSpotLimitOrder"));
+    }
+
+    #[test]
+    fn deserialize_unknown_authorization() {
+        let json = r#"{"@type":"/cosmos.bank.v1beta1.SendAuthorization","spend_limit":[]}"#;
+        let authorization: Authorization = serde_json_wasm::from_str(json).unwrap();
+
+        assert_eq!(authorization, Authorization::Unknown);
+        assert_eq!(authorization.msg_type_url(), None);
+    }
+}
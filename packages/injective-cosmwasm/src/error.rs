@@ -0,0 +1,141 @@
+use std::fmt;
+
+use cosmwasm_std::StdError;
+use injective_math::FPDecimal;
+
+use crate::exchange::{market::MarketStatus, order::OrderType, types::MarketId};
+use crate::oracle::types::PythStatus;
+
+/// Errors returned by injective-cosmwasm helpers, convertible into `StdError` for contracts that only use that
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InjectiveError {
+    InvalidSubaccountId(String),
+    InvalidMarketId(String),
+    QueryFailed(String),
+    InvalidBech32 {
+        address: String,
+        reason: String,
+    },
+    InvalidEthereumAddress {
+        address: String,
+        reason: String,
+    },
+    ExternalTransferFromDefaultSubaccount,
+    InvalidOrderSide,
+    MarketNotFound {
+        market_id: MarketId,
+    },
+    MarketNotActive {
+        market_id: MarketId,
+        status: MarketStatus,
+    },
+    MissingDenomDecimals {
+        denom: String,
+    },
+    InvalidOrderType {
+        order_type: OrderType,
+    },
+    InvalidPrice {
+        price: FPDecimal,
+    },
+    PriceBelowMinTick {
+        price: FPDecimal,
+        min_price_tick_size: FPDecimal,
+    },
+    InvalidQuantity {
+        quantity: FPDecimal,
+    },
+    QuantityBelowMinTick {
+        quantity: FPDecimal,
+        min_quantity_tick_size: FPDecimal,
+    },
+    InvalidMargin {
+        margin: FPDecimal,
+    },
+    InvalidTokenFactoryDenom {
+        denom: String,
+        reason: String,
+    },
+    InvalidContractRegistration {
+        contract_address: String,
+        reason: String,
+    },
+    CidTooLong {
+        cid: String,
+        max_length: usize,
+    },
+    CidCounterExhausted,
+    PythPriceNotTrading {
+        price_id: String,
+        status: PythStatus,
+    },
+    InvalidPythPrice {
+        price_id: String,
+        price: FPDecimal,
+    },
+    StalePythPrice {
+        price_id: String,
+        age: u64,
+        max_age: u64,
+    },
+    PythConfidenceTooWide {
+        price_id: String,
+        confidence_ratio: FPDecimal,
+        max_confidence_ratio: FPDecimal,
+    },
+    TradeNonceExhausted,
+}
+
+impl fmt::Display for InjectiveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InjectiveError::InvalidSubaccountId(msg) => write!(f, "{msg}"),
+            InjectiveError::InvalidMarketId(msg) => write!(f, "{msg}"),
+            InjectiveError::QueryFailed(msg) => write!(f, "Query failed: {msg}"),
+            InjectiveError::InvalidBech32 { address, reason } => write!(f, "Invalid bech32 address {address}: {reason}"),
+            InjectiveError::InvalidEthereumAddress { address, reason } => write!(f, "Invalid ethereum address {address}: {reason}"),
+            InjectiveError::ExternalTransferFromDefaultSubaccount => write!(f, "Cannot send from default subaccount to external subaccount"),
+            InjectiveError::InvalidOrderSide => write!(f, "Order side must be buy or sell"),
+            InjectiveError::MarketNotFound { market_id } => write!(f, "Market {} not found", market_id.as_str()),
+            InjectiveError::MarketNotActive { market_id, status } => write!(f, "Market {} is not active (status {:?})", market_id.as_str(), status),
+            InjectiveError::MissingDenomDecimals { denom } => write!(f, "Decimals of denom {denom} not found"),
+            InjectiveError::InvalidOrderType { order_type } => write!(f, "Invalid order type {order_type:?}"),
+            InjectiveError::InvalidPrice { price } => write!(f, "Price must be positive, got {price}"),
+            InjectiveError::PriceBelowMinTick { price, min_price_tick_size } => {
+                write!(f, "Price {price} is below min price tick size {min_price_tick_size}")
+            }
+            InjectiveError::InvalidQuantity { quantity } => write!(f, "Quantity must be positive, got {quantity}"),
+            InjectiveError::QuantityBelowMinTick {
+                quantity,
+                min_quantity_tick_size,
+            } => write!(f, "Quantity {quantity} is below min quantity tick size {min_quantity_tick_size}"),
+            InjectiveError::InvalidMargin { margin } => write!(f, "Margin cannot be negative, got {margin}"),
+            InjectiveError::InvalidTokenFactoryDenom { denom, reason } => write!(f, "Invalid tokenfactory denom {denom}: {reason}"),
+            InjectiveError::InvalidContractRegistration { contract_address, reason } => {
+                write!(f, "Invalid registration of contract {contract_address}: {reason}")
+            }
+            InjectiveError::CidTooLong { cid, max_length } => write!(f, "Client order id {cid} is longer than {max_length} characters"),
+            InjectiveError::CidCounterExhausted => write!(f, "Client order id counter is exhausted"),
+            InjectiveError::PythPriceNotTrading { price_id, status } => write!(f, "Pyth price {price_id} is not trading (status {status:?})"),
+            InjectiveError::InvalidPythPrice { price_id, price } => write!(f, "Pyth price {price_id} must be positive, got {price}"),
+            InjectiveError::StalePythPrice { price_id, age, max_age } => write!(f, "Pyth price {price_id} is {age}s old, max age is {max_age}s"),
+            InjectiveError::PythConfidenceTooWide {
+                price_id,
+                confidence_ratio,
+                max_confidence_ratio,
+            } => write!(
+                f,
+                "Pyth price {price_id} confidence ratio {confidence_ratio} exceeds {max_confidence_ratio}"
+            ),
+            InjectiveError::TradeNonceExhausted => write!(f, "Subaccount trade nonce is exhausted"),
+        }
+    }
+}
+
+impl std::error::Error for InjectiveError {}
+
+impl From<InjectiveError> for StdError {
+    fn from(err: InjectiveError) -> Self {
+        StdError::generic_err(err.to_string())
+    }
+}
@@ -0,0 +1,218 @@
+use std::collections::BTreeMap;
+
+use cosmwasm_std::StdResult;
+use injective_math::FPDecimal;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::exchange::{
+    derivative::{DerivativePosition, TrimmedDerivativeLimitOrder},
+    order::GenericTrimmedOrder,
+    spot::TrimmedSpotLimitOrder,
+    types::{Deposit, MarketId, SubaccountId},
+};
+use crate::querier::InjectiveQuerier;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct MarketOrders<T> {
+    pub market_id: MarketId,
+    pub orders: Vec<T>,
+}
+
+/// Deposits, positions and open orders of a subaccount, see [`AccountSnapshotBuilder`].
+/// Totals over positions and orders add up amounts of every market, which should therefore share a quote denom.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct AccountSnapshot {
+    pub subaccount_id: SubaccountId,
+    pub deposits: BTreeMap<String, Deposit>,
+    pub positions: Vec<DerivativePosition>,
+    pub spot_orders: Vec<MarketOrders<TrimmedSpotLimitOrder>>,
+    pub derivative_orders: Vec<MarketOrders<TrimmedDerivativeLimitOrder>>,
+}
+
+impl AccountSnapshot {
+    pub fn builder(subaccount_id: SubaccountId) -> AccountSnapshotBuilder {
+        AccountSnapshotBuilder::new(subaccount_id)
+    }
+
+    /// Balance of `denom` not held by open orders
+    pub fn free_collateral(&self, denom: &str) -> FPDecimal {
+        self.deposits.get(denom).map_or(FPDecimal::ZERO, |deposit| deposit.available_balance)
+    }
+
+    pub fn total_balance(&self, denom: &str) -> FPDecimal {
+        self.deposits.get(denom).map_or(FPDecimal::ZERO, |deposit| deposit.total_balance)
+    }
+
+    pub fn position_margin(&self) -> FPDecimal {
+        self.positions.iter().fold(FPDecimal::ZERO, |total, p| total + p.position.margin)
+    }
+
+    /// Margin held by the unfilled part of open derivative orders, reduce-only orders hold none
+    pub fn order_margin(&self) -> FPDecimal {
+        self.derivative_orders
+            .iter()
+            .flat_map(|market| market.orders.iter())
+            .filter(|order| !order.quantity.is_zero())
+            .fold(FPDecimal::ZERO, |total, order| total + order.margin * order.fillable / order.quantity)
+    }
+
+    /// Quote notional held by the unfilled part of open spot buy orders, fees excluded
+    pub fn spot_order_notional(&self) -> FPDecimal {
+        self.spot_orders
+            .iter()
+            .flat_map(|market| market.orders.iter())
+            .filter(|order| order.is_buy())
+            .fold(FPDecimal::ZERO, |total, order| total + order.get_price() * order.get_fillable_quantity())
+    }
+
+    pub fn find_position(&self, market_id: &MarketId) -> Option<&DerivativePosition> {
+        self.positions.iter().find(|p| &p.market_id == market_id)
+    }
+}
+
+/// Collects an [`AccountSnapshot`] with one query for deposits, one for positions and one per market for open orders
+pub struct AccountSnapshotBuilder {
+    subaccount_id: SubaccountId,
+    spot_market_ids: Vec<MarketId>,
+    derivative_market_ids: Vec<MarketId>,
+}
+
+impl AccountSnapshotBuilder {
+    pub fn new(subaccount_id: SubaccountId) -> Self {
+        AccountSnapshotBuilder {
+            subaccount_id,
+            spot_market_ids: vec![],
+            derivative_market_ids: vec![],
+        }
+    }
+
+    pub fn spot_markets(mut self, market_ids: impl IntoIterator<Item = MarketId>) -> Self {
+        self.spot_market_ids.extend(market_ids);
+        self
+    }
+
+    pub fn derivative_markets(mut self, market_ids: impl IntoIterator<Item = MarketId>) -> Self {
+        self.derivative_market_ids.extend(market_ids);
+        self
+    }
+
+    pub fn query(self, querier: &InjectiveQuerier) -> StdResult<AccountSnapshot> {
+        let deposits = querier.query_subaccount_deposits(&self.subaccount_id)?.deposits;
+        let positions = querier.query_subaccount_positions(&self.subaccount_id)?.state;
+
+        let spot_orders = self
+            .spot_market_ids
+            .into_iter()
+            .map(|market_id| {
+                let orders = querier.query_trader_spot_orders(&market_id, &self.subaccount_id)?.orders;
+                Ok(MarketOrders {
+                    market_id,
+                    orders: orders.unwrap_or_default(),
+                })
+            })
+            .collect::<StdResult<Vec<_>>>()?;
+
+        let derivative_orders = self
+            .derivative_market_ids
+            .into_iter()
+            .map(|market_id| {
+                let orders = querier.query_trader_derivative_orders(&market_id, &self.subaccount_id)?.orders;
+                Ok(MarketOrders {
+                    market_id,
+                    orders: orders.unwrap_or_default(),
+                })
+            })
+            .collect::<StdResult<Vec<_>>>()?;
+
+        Ok(AccountSnapshot {
+            subaccount_id: self.subaccount_id,
+            deposits,
+            positions,
+            spot_orders,
+            derivative_orders,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use injective_math::FPDecimal;
+
+    use crate::exchange::{
+        account_snapshot::AccountSnapshot,
+        derivative::{DerivativePosition, Position, TrimmedDerivativeLimitOrder},
+        spot::TrimmedSpotLimitOrder,
+        types::{Deposit, MarketId, SubaccountId},
+    };
+    use crate::exchange_mock_querier::{handlers, mock_dependencies, TestDeposit};
+    use crate::querier::InjectiveQuerier;
+
+    const SUBACCOUNT_ID: &str = "0xaf79152ac5df276d9a8e1e2e22822f9713474902000000000000000000000000";
+    const OTHER_SUBACCOUNT_ID: &str = "0xaf79152ac5df276d9a8e1e2e22822f9713474902000000000000000000000001";
+    const SPOT_MARKET_ID: &str = "0x0611780ba69656949525013d947713300f56c37b6175e02f26bffa495c3208fe";
+    const DERIVATIVE_MARKET_ID: &str = "0x01edfab47f124748dc89998eb33144af734484ba07099014594321729a0ca16b";
+
+    #[test]
+    fn snapshot_combines_deposits_positions_and_orders() {
+        let subaccount_id = SubaccountId::unchecked(SUBACCOUNT_ID);
+        let mut deps = mock_dependencies();
+        deps.querier.subaccount_deposits_response_handler = handlers::create_subaccount_deposits_handler(HashMap::from([(
+            subaccount_id.clone(),
+            vec![TestDeposit::new(
+                Deposit {
+                    available_balance: FPDecimal::must_from_str("100"),
+                    total_balance: FPDecimal::must_from_str("160"),
+                },
+                "usdt".to_string(),
+            )],
+        )]));
+        deps.querier.subaccount_positions_response_handler = handlers::create_subaccount_positions_handler(vec![DerivativePosition {
+            subaccount_id: subaccount_id.clone(),
+            market_id: MarketId::unchecked(DERIVATIVE_MARKET_ID),
+            position: Position {
+                isLong: true,
+                quantity: FPDecimal::ONE,
+                entry_price: FPDecimal::must_from_str("20"),
+                margin: FPDecimal::must_from_str("25"),
+                cumulative_funding_entry: FPDecimal::ZERO,
+            },
+        }]);
+        deps.querier.trader_spot_orders_response_handler = handlers::create_trader_spot_orders_handler(Some(vec![TrimmedSpotLimitOrder {
+            price: FPDecimal::must_from_str("10"),
+            quantity: FPDecimal::must_from_str("3"),
+            fillable: FPDecimal::TWO,
+            isBuy: true,
+            order_hash: "0x01".to_string(),
+        }]));
+        deps.querier.trader_derivative_orders_response_handler =
+            handlers::create_trader_derivative_orders_handler(Some(vec![TrimmedDerivativeLimitOrder {
+                price: FPDecimal::must_from_str("20"),
+                quantity: FPDecimal::TWO,
+                margin: FPDecimal::must_from_str("20"),
+                fillable: FPDecimal::ONE,
+                isBuy: true,
+                order_hash: "0x02".to_string(),
+            }]));
+
+        let deps = deps.as_ref();
+        let querier = InjectiveQuerier::new(&deps.querier);
+        let snapshot = AccountSnapshot::builder(subaccount_id)
+            .spot_markets(vec![MarketId::unchecked(SPOT_MARKET_ID)])
+            .derivative_markets(vec![MarketId::unchecked(DERIVATIVE_MARKET_ID)])
+            .query(&querier)
+            .unwrap();
+
+        assert_eq!(snapshot.free_collateral("usdt"), FPDecimal::must_from_str("100"));
+        assert_eq!(snapshot.free_collateral("inj"), FPDecimal::ZERO);
+        assert_eq!(snapshot.position_margin(), FPDecimal::must_from_str("25"));
+        assert_eq!(snapshot.order_margin(), FPDecimal::must_from_str("10"));
+        assert_eq!(snapshot.spot_order_notional(), FPDecimal::must_from_str("20"));
+        assert!(snapshot.find_position(&MarketId::unchecked(DERIVATIVE_MARKET_ID)).is_some());
+
+        let other_subaccount_id = SubaccountId::unchecked(OTHER_SUBACCOUNT_ID);
+        assert!(querier.query_subaccount_deposits(&other_subaccount_id).unwrap().deposits.is_empty());
+    }
+}
@@ -0,0 +1,108 @@
+use crate::exchange::types::MarketId;
+use crate::oracle::types::OracleType;
+use injective_math::FPDecimal;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::market::{GenericMarket, MarketStatus};
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct BinaryOptionsMarket {
+    pub ticker: String,
+    pub oracle_symbol: String,
+    pub oracle_provider: String,
+    #[serde(default)]
+    pub oracle_type: OracleType,
+    #[serde(default)]
+    pub oracle_scale_factor: u32,
+    pub expiration_timestamp: i64,
+    pub settlement_timestamp: i64,
+    pub admin: String,
+    pub quote_denom: String,
+    pub market_id: MarketId,
+    pub maker_fee_rate: FPDecimal,
+    pub taker_fee_rate: FPDecimal,
+    pub relayer_fee_share_rate: FPDecimal,
+    #[serde(default)]
+    pub status: MarketStatus,
+    pub min_price_tick_size: FPDecimal,
+    pub min_quantity_tick_size: FPDecimal,
+    pub settlement_price: Option<FPDecimal>,
+}
+
+impl GenericMarket for BinaryOptionsMarket {
+    fn get_ticker(&self) -> &str {
+        &self.ticker
+    }
+
+    fn get_quote_denom(&self) -> &str {
+        &self.quote_denom
+    }
+
+    fn get_maker_fee_rate(&self) -> FPDecimal {
+        self.maker_fee_rate
+    }
+
+    fn get_taker_fee_rate(&self) -> FPDecimal {
+        self.taker_fee_rate
+    }
+
+    fn get_market_id(&self) -> &MarketId {
+        &self.market_id
+    }
+
+    fn get_status(&self) -> MarketStatus {
+        self.status
+    }
+
+    fn get_min_price_tick_size(&self) -> FPDecimal {
+        self.min_price_tick_size
+    }
+
+    fn min_quantity_tick_size(&self) -> FPDecimal {
+        self.min_quantity_tick_size
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{from_json, to_json_vec};
+    use injective_math::FPDecimal;
+
+    use crate::exchange::{binary_options_market::BinaryOptionsMarket, market::MarketStatus};
+    use crate::oracle::types::OracleType;
+
+    #[test]
+    fn binary_options_market_json_round_trip() {
+        let json = r#"{
+            "ticker": "BTC/USDT 100k",
+            "oracle_symbol": "BTC",
+            "oracle_provider": "frontrunner",
+            "oracle_type": 11,
+            "oracle_scale_factor": 6,
+            "expiration_timestamp": 1700000000,
+            "settlement_timestamp": 1700003600,
+            "admin": "inj1k2z3chspuk9wsufle69svmtmnlc07rvw9djya7",
+            "quote_denom": "peggy0xdAC17F958D2ee523a2206206994597C13D831ec7",
+            "market_id": "0x01edfab47f124748dc89998eb33144af734484ba07099014594321729a0ca16b",
+            "maker_fee_rate": "-0.0001",
+            "taker_fee_rate": "0.001",
+            "relayer_fee_share_rate": "0.4",
+            "status": 1,
+            "min_price_tick_size": "0.01",
+            "min_quantity_tick_size": "1",
+            "settlement_price": null
+        }"#;
+
+        let market: BinaryOptionsMarket = from_json(json).unwrap();
+
+        assert_eq!(market.oracle_type, OracleType::Provider);
+        assert_eq!(market.status, MarketStatus::Active);
+        assert_eq!(market.maker_fee_rate, FPDecimal::must_from_str("-0.0001"));
+        assert_eq!(market.settlement_price, None);
+        assert_eq!(from_json::<BinaryOptionsMarket>(to_json_vec(&market).unwrap()).unwrap(), market);
+
+        let settled: BinaryOptionsMarket = from_json(json.replace(r#""settlement_price": null"#, r#""settlement_price": "1""#)).unwrap();
+        assert_eq!(settled.settlement_price, Some(FPDecimal::ONE));
+    }
+}
@@ -0,0 +1,129 @@
+use cosmwasm_std::{Addr, Env};
+use tiny_keccak::Keccak;
+
+use crate::InjectiveError;
+
+/// Longest client order id accepted by the exchange module
+pub const MAX_CID_LENGTH: usize = 36;
+
+/// Generates client order ids of the form `{address hash}-{block height}[-{transaction index}]-{counter}`, rejecting ids
+/// longer than [`MAX_CID_LENGTH`]. Ids are unique per generator, contracts executed several times within the same transaction
+/// should persist `counter()` and resume with `starting_at`.
+pub struct CidGenerator {
+    prefix: String,
+    block_height: u64,
+    transaction_index: Option<u32>,
+    counter: u32,
+}
+
+impl CidGenerator {
+    pub fn new(contract_address: &Addr, block_height: u64) -> Self {
+        let mut hasher = Keccak::new_keccak256();
+        hasher.update(contract_address.as_bytes());
+        let mut res = [0u8; 32];
+        hasher.finalize(&mut res);
+
+        CidGenerator {
+            prefix: hex::encode(&res[..4]),
+            block_height,
+            transaction_index: None,
+            counter: 0,
+        }
+    }
+
+    /// Includes the transaction index, so executions in different transactions of a block do not collide
+    pub fn from_env(env: &Env) -> Self {
+        let mut generator = CidGenerator::new(&env.contract.address, env.block.height);
+        generator.transaction_index = env.transaction.as_ref().map(|transaction| transaction.index);
+        generator
+    }
+
+    pub fn starting_at(mut self, counter: u32) -> Self {
+        self.counter = counter;
+        self
+    }
+
+    pub fn counter(&self) -> u32 {
+        self.counter
+    }
+
+    /// Fails without advancing the counter when the id would be too long for the exchange module or the counter is exhausted
+    pub fn next_cid(&mut self) -> Result<String, InjectiveError> {
+        let next_counter = self.counter.checked_add(1).ok_or(InjectiveError::CidCounterExhausted)?;
+        let cid = match self.transaction_index {
+            Some(index) => format!("{}-{}-{}-{}", self.prefix, self.block_height, index, self.counter),
+            None => format!("{}-{}-{}", self.prefix, self.block_height, self.counter),
+        };
+        if cid.len() > MAX_CID_LENGTH {
+            return Err(InjectiveError::CidTooLong {
+                cid,
+                max_length: MAX_CID_LENGTH,
+            });
+        }
+
+        self.counter = next_counter;
+        Ok(cid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{testing::mock_env, Addr, TransactionInfo};
+
+    use crate::exchange::cid::{CidGenerator, MAX_CID_LENGTH};
+    use crate::InjectiveError;
+
+    #[test]
+    fn cids_are_unique_and_short() {
+        let mut generator = CidGenerator::new(&Addr::unchecked("inj1cml96vmptgw99syqrrz8az79xer2pcgp0a885r"), 57_000_000);
+
+        let first = generator.next_cid().unwrap();
+        let second = generator.next_cid().unwrap();
+
+        assert_ne!(first, second);
+        assert!(first.ends_with("-57000000-0"));
+        assert_eq!(generator.counter(), 2);
+        assert!(first.len() <= MAX_CID_LENGTH);
+    }
+
+    #[test]
+    fn transactions_of_a_block_use_distinct_counters() {
+        let mut env = mock_env();
+        env.transaction = Some(TransactionInfo { index: 1 });
+        let mut first_tx = CidGenerator::from_env(&env);
+        env.transaction = Some(TransactionInfo { index: 2 });
+        let mut second_tx = CidGenerator::from_env(&env);
+
+        assert_ne!(first_tx.next_cid().unwrap(), second_tx.next_cid().unwrap());
+        assert!(second_tx.next_cid().unwrap().len() <= MAX_CID_LENGTH);
+    }
+
+    #[test]
+    fn ids_over_the_length_limit_or_past_the_last_counter_are_rejected() {
+        let address = Addr::unchecked("inj1cml96vmptgw99syqrrz8az79xer2pcgp0a885r");
+        let mut env = mock_env();
+        env.contract.address = address.clone();
+        env.block.height = u64::MAX;
+        env.transaction = Some(TransactionInfo { index: u32::MAX });
+
+        let mut longest = CidGenerator::from_env(&env).starting_at(u32::MAX);
+        assert_eq!(longest.next_cid(), Err(InjectiveError::CidCounterExhausted));
+
+        let mut too_long = CidGenerator::from_env(&env).starting_at(u32::MAX - 1);
+        assert!(matches!(
+            too_long.next_cid(),
+            Err(InjectiveError::CidTooLong {
+                max_length: MAX_CID_LENGTH,
+                ..
+            })
+        ));
+        assert_eq!(too_long.counter(), u32::MAX - 1);
+
+        let mut last = CidGenerator::new(&address, 1).starting_at(u32::MAX - 1);
+        assert!(last.next_cid().unwrap().ends_with(&format!("-1-{}", u32::MAX - 1)));
+        assert_eq!(last.next_cid(), Err(InjectiveError::CidCounterExhausted));
+
+        let mut highest_block = CidGenerator::new(&address, u64::MAX);
+        assert!(highest_block.next_cid().unwrap().len() <= MAX_CID_LENGTH);
+    }
+}
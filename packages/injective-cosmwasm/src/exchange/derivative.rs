@@ -4,6 +4,7 @@ use serde::{Deserialize, Serialize};
 
 use injective_math::FPDecimal;
 
+use crate::exchange::derivative_market::{DerivativeMarket, PerpetualMarketFunding};
 use crate::exchange::order::{GenericOrder, OrderInfo, OrderType};
 use crate::exchange::types::{MarketId, SubaccountId};
 use crate::ShortSubaccountId;
@@ -57,6 +58,89 @@ impl Position {
         self.margin += unrealized_funding;
         self.cumulative_funding_entry = cumulative_funding;
     }
+
+    /// Margin left once pending funding is settled, funding is ignored when `None` (expiry futures)
+    pub fn get_margin_with_funding(&self, funding: Option<&PerpetualMarketFunding>) -> FPDecimal {
+        self.get_position_value_with_funding(self.entry_price, self.cumulative_funding(funding))
+    }
+
+    /// PnL at `mark_price`, including the funding accrued since the position was last updated
+    pub fn get_unrealized_pnl(&self, mark_price: FPDecimal, funding: Option<&PerpetualMarketFunding>) -> FPDecimal {
+        self.get_position_value_with_funding(mark_price, self.cumulative_funding(funding)) - self.margin
+    }
+
+    /// Price at which the position's value drops to zero, `None` for an empty position
+    pub fn get_bankruptcy_price(&self, funding: Option<&PerpetualMarketFunding>) -> Option<FPDecimal> {
+        if self.quantity.is_zero() {
+            return None;
+        }
+
+        let margin_per_unit = self.get_margin_with_funding(funding) / self.quantity;
+
+        if self.isLong {
+            Some((self.entry_price - margin_per_unit).max(FPDecimal::ZERO))
+        } else {
+            Some(self.entry_price + margin_per_unit)
+        }
+    }
+
+    /// Price at which the position's margin ratio falls to the market's maintenance margin ratio, `None` for an empty position
+    pub fn get_liquidation_price(&self, market: &DerivativeMarket, funding: Option<&PerpetualMarketFunding>) -> Option<FPDecimal> {
+        let margin = self.get_margin_with_funding(funding);
+        let entry_notional = self.quantity * self.entry_price;
+
+        let (numerator, ratio_factor) = if self.isLong {
+            (entry_notional - margin, FPDecimal::ONE - market.maintenance_margin_ratio)
+        } else {
+            (entry_notional + margin, FPDecimal::ONE + market.maintenance_margin_ratio)
+        };
+
+        let denominator = self.quantity * ratio_factor;
+        if denominator.is_zero() {
+            return None;
+        }
+
+        let price = numerator / denominator;
+        Some(if self.isLong { price.max(FPDecimal::ZERO) } else { price })
+    }
+
+    /// Position value over notional at `mark_price`, `None` when the notional is zero
+    pub fn get_margin_ratio(&self, mark_price: FPDecimal, funding: Option<&PerpetualMarketFunding>) -> Option<FPDecimal> {
+        let notional = self.quantity * mark_price;
+        if notional.is_zero() {
+            return None;
+        }
+
+        Some(self.get_position_value_with_funding(mark_price, self.cumulative_funding(funding)) / notional)
+    }
+
+    /// Notional at `mark_price` over position value, `None` once the position has no value left
+    pub fn get_effective_leverage(&self, mark_price: FPDecimal, funding: Option<&PerpetualMarketFunding>) -> Option<FPDecimal> {
+        let value = self.get_position_value_with_funding(mark_price, self.cumulative_funding(funding));
+        if value <= FPDecimal::ZERO {
+            return None;
+        }
+
+        Some(self.quantity * mark_price / value)
+    }
+
+    /// A position without notional at `mark_price` has nothing left to liquidate
+    pub fn is_liquidatable(&self, market: &DerivativeMarket, mark_price: FPDecimal, funding: Option<&PerpetualMarketFunding>) -> bool {
+        self.get_margin_ratio(mark_price, funding)
+            .is_some_and(|margin_ratio| margin_ratio < market.maintenance_margin_ratio)
+    }
+
+    /// Whether the position would still be allowed to open, e.g. after removing margin
+    pub fn meets_initial_margin(&self, market: &DerivativeMarket, mark_price: FPDecimal, funding: Option<&PerpetualMarketFunding>) -> bool {
+        match self.get_margin_ratio(mark_price, funding) {
+            Some(margin_ratio) => margin_ratio >= market.initial_margin_ratio,
+            None => true,
+        }
+    }
+
+    fn cumulative_funding(&self, funding: Option<&PerpetualMarketFunding>) -> FPDecimal {
+        funding.map_or(self.cumulative_funding_entry, |funding| funding.cumulative_funding)
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
@@ -385,3 +469,121 @@ impl GenericTrimmedOrder for TrimmedDerivativeLimitOrder {
         self.order_hash.to_owned()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use injective_math::FPDecimal;
+
+    use crate::exchange::derivative::Position;
+    use crate::exchange::derivative_market::{DerivativeMarket, PerpetualMarketFunding};
+    use crate::exchange::market::MarketStatus;
+    use crate::oracle::types::OracleType;
+    use crate::MarketId;
+
+    fn market(maintenance_margin_ratio: &str) -> DerivativeMarket {
+        DerivativeMarket {
+            ticker: "INJ/USDT PERP".to_string(),
+            oracle_base: "inj".to_string(),
+            oracle_quote: "usdt".to_string(),
+            oracle_type: OracleType::PriceFeed,
+            oracle_scale_factor: 0,
+            quote_denom: "usdt".to_string(),
+            market_id: MarketId::unchecked("0x01edfab47f124748dc89998eb33144af734484ba07099014594321729a0ca16b"),
+            initial_margin_ratio: FPDecimal::must_from_str("0.3"),
+            maintenance_margin_ratio: FPDecimal::must_from_str(maintenance_margin_ratio),
+            maker_fee_rate: FPDecimal::ZERO,
+            taker_fee_rate: FPDecimal::ZERO,
+            isPerpetual: true,
+            status: MarketStatus::Active,
+            min_price_tick_size: FPDecimal::must_from_str("0.01"),
+            min_quantity_tick_size: FPDecimal::must_from_str("0.01"),
+        }
+    }
+
+    fn position(is_long: bool, quantity: &str, margin: &str) -> Position {
+        Position {
+            isLong: is_long,
+            quantity: FPDecimal::must_from_str(quantity),
+            entry_price: FPDecimal::must_from_str("100"),
+            margin: FPDecimal::must_from_str(margin),
+            cumulative_funding_entry: FPDecimal::ONE,
+        }
+    }
+
+    fn funding(cumulative_funding: &str) -> PerpetualMarketFunding {
+        PerpetualMarketFunding {
+            cumulative_funding: FPDecimal::must_from_str(cumulative_funding),
+            cumulative_price: FPDecimal::ZERO,
+            last_timestamp: 0,
+        }
+    }
+
+    #[test]
+    fn long_position_risk_without_funding() {
+        let market = market("0.2");
+        let position = position(true, "2", "50");
+        let mark_price = FPDecimal::must_from_str("150");
+
+        assert_eq!(position.get_bankruptcy_price(None), Some(FPDecimal::must_from_str("75")));
+        assert_eq!(position.get_liquidation_price(&market, None), Some(FPDecimal::must_from_str("93.75")));
+        assert_eq!(position.get_unrealized_pnl(mark_price, None), FPDecimal::must_from_str("100"));
+        assert_eq!(position.get_margin_ratio(mark_price, None), Some(FPDecimal::must_from_str("0.5")));
+        assert_eq!(position.get_effective_leverage(mark_price, None), Some(FPDecimal::TWO));
+        assert!(!position.is_liquidatable(&market, mark_price, None));
+        assert!(position.meets_initial_margin(&market, mark_price, None));
+    }
+
+    #[test]
+    fn long_position_pays_funding() {
+        let market = market("0.2");
+        let position = position(true, "2", "50");
+        let funding = funding("3");
+
+        assert_eq!(position.get_margin_with_funding(Some(&funding)), FPDecimal::must_from_str("46"));
+        assert_eq!(position.get_bankruptcy_price(Some(&funding)), Some(FPDecimal::must_from_str("77")));
+        assert_eq!(
+            position.get_liquidation_price(&market, Some(&funding)),
+            Some(FPDecimal::must_from_str("96.25"))
+        );
+        assert_eq!(
+            position.get_unrealized_pnl(FPDecimal::must_from_str("150"), Some(&funding)),
+            FPDecimal::must_from_str("96")
+        );
+        assert!(position.is_liquidatable(&market, FPDecimal::must_from_str("96"), Some(&funding)));
+    }
+
+    #[test]
+    fn short_position_receives_funding() {
+        let market = market("0.25");
+        let position = position(false, "1", "50");
+
+        assert_eq!(position.get_bankruptcy_price(None), Some(FPDecimal::must_from_str("150")));
+        assert_eq!(position.get_liquidation_price(&market, None), Some(FPDecimal::must_from_str("120")));
+        assert_eq!(
+            position.get_liquidation_price(&market, Some(&funding("3"))),
+            Some(FPDecimal::must_from_str("121.6"))
+        );
+        assert_eq!(position.get_effective_leverage(FPDecimal::must_from_str("150"), None), None);
+    }
+
+    #[test]
+    fn empty_position_has_no_risk_prices() {
+        let market = market("0.2");
+        let position = position(true, "0", "50");
+        let mark_price = FPDecimal::must_from_str("150");
+
+        assert_eq!(position.get_bankruptcy_price(None), None);
+        assert_eq!(position.get_liquidation_price(&market, None), None);
+        assert_eq!(position.get_margin_ratio(mark_price, None), None);
+        assert!(!position.is_liquidatable(&market, mark_price, None));
+        assert!(position.meets_initial_margin(&market, mark_price, None));
+    }
+
+    #[test]
+    fn degenerate_risk_inputs_return_none() {
+        let position = position(true, "2", "50");
+
+        assert_eq!(position.get_margin_ratio(FPDecimal::ZERO, None), None);
+        assert_eq!(position.get_liquidation_price(&market("1"), None), None);
+    }
+}
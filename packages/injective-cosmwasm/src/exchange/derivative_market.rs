@@ -1,5 +1,7 @@
+use crate::exchange::derivative::Position;
 use crate::exchange::types::MarketId;
 use crate::oracle::types::OracleType;
+use cosmwasm_std::Timestamp;
 use injective_math::FPDecimal;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -28,24 +30,143 @@ pub struct PerpetualMarketFunding {
     pub last_timestamp: i64,
 }
 
+impl PerpetualMarketInfo {
+    pub fn funding_interval_start(&self) -> i64 {
+        self.next_funding_timestamp - self.funding_interval
+    }
+
+    /// Hourly funding rate the chain would apply at `next_funding_timestamp` given the funding accumulated so far:
+    /// the premium TWAP plus the hourly interest rate, clamped to +/- `hourly_funding_rate_cap`
+    pub fn estimate_funding_rate(&self, funding: &PerpetualMarketFunding) -> FPDecimal {
+        let rate = funding.premium_twap(self.funding_interval_start()) + self.hourly_interest_rate;
+        let cap = self.hourly_funding_rate_cap;
+
+        rate.min(cap).max(-cap)
+    }
+
+    /// Cumulative funding once the next funding is applied at `mark_price`
+    pub fn estimate_next_cumulative_funding(&self, funding: &PerpetualMarketFunding, mark_price: FPDecimal) -> FPDecimal {
+        funding.cumulative_funding + self.estimate_funding_rate(funding) * mark_price
+    }
+
+    /// Funding credited to (positive) or debited from (negative) the position's margin at `next_funding_timestamp`,
+    /// including any funding still pending since the position was last updated
+    pub fn project_funding_payment(&self, funding: &PerpetualMarketFunding, position: &Position, mark_price: FPDecimal) -> FPDecimal {
+        let next_cumulative_funding = self.estimate_next_cumulative_funding(funding, mark_price);
+
+        position.get_position_value_with_funding(position.entry_price, next_cumulative_funding) - position.margin
+    }
+}
+
+impl PerpetualMarketFunding {
+    /// Time weighted average of the mark/index premium accumulated in `cumulative_price` since `interval_start`, expressed hourly
+    pub fn premium_twap(&self, interval_start: i64) -> FPDecimal {
+        let elapsed = self.last_timestamp - interval_start;
+        if elapsed <= 0 {
+            return FPDecimal::ZERO;
+        }
+
+        self.cumulative_price / FPDecimal::from(i128::from(elapsed) * 24)
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 pub struct PerpetualMarketState {
     pub market_info: PerpetualMarketInfo,
     pub funding_info: PerpetualMarketFunding,
 }
 
+impl PerpetualMarketState {
+    pub fn estimate_funding_rate(&self) -> FPDecimal {
+        self.market_info.estimate_funding_rate(&self.funding_info)
+    }
+
+    pub fn project_funding_payment(&self, position: &Position, mark_price: FPDecimal) -> FPDecimal {
+        self.market_info.project_funding_payment(&self.funding_info, position, mark_price)
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct ExpiryFuturesMarketInfo {
+    pub market_id: MarketId,
+    #[serde(default)]
+    pub expiration_timestamp: i64,
+    #[serde(default)]
+    pub twap_start_timestamp: i64,
+    #[serde(default)]
+    pub expiration_twap_start_price_cumulative: FPDecimal,
+    #[serde(default)]
+    pub settlement_price: FPDecimal,
+}
+
+impl ExpiryFuturesMarketInfo {
+    pub fn is_expired(&self, now: Timestamp) -> bool {
+        now.seconds() as i64 >= self.expiration_timestamp
+    }
+
+    /// Seconds left until expiration, zero once the market has expired
+    pub fn time_to_expiry(&self, now: Timestamp) -> u64 {
+        (self.expiration_timestamp - now.seconds() as i64).max(0) as u64
+    }
+
+    pub fn is_settled(&self) -> bool {
+        !self.settlement_price.is_zero()
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 pub struct FullDerivativeMarketPerpetualInfo {
     pub perpetual_info: PerpetualMarketState,
 }
 
+/// Market specific info of a derivative market, mirrors the `info` oneof of the chain's FullDerivativeMarket
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum FullDerivativeMarketInfo {
+    PerpetualInfo(PerpetualMarketState),
+    FuturesInfo(ExpiryFuturesMarketInfo),
+}
+
+impl From<FullDerivativeMarketPerpetualInfo> for FullDerivativeMarketInfo {
+    fn from(info: FullDerivativeMarketPerpetualInfo) -> Self {
+        FullDerivativeMarketInfo::PerpetualInfo(info.perpetual_info)
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 pub struct FullDerivativeMarket {
     pub market: Option<DerivativeMarket>,
-    pub info: Option<FullDerivativeMarketPerpetualInfo>,
+    pub info: Option<FullDerivativeMarketInfo>,
     pub mark_price: FPDecimal,
 }
 
+impl FullDerivativeMarket {
+    pub fn perpetual_info(&self) -> Option<&PerpetualMarketState> {
+        match &self.info {
+            Some(FullDerivativeMarketInfo::PerpetualInfo(state)) => Some(state),
+            _ => None,
+        }
+    }
+
+    pub fn expiry_futures_info(&self) -> Option<&ExpiryFuturesMarketInfo> {
+        match &self.info {
+            Some(FullDerivativeMarketInfo::FuturesInfo(info)) => Some(info),
+            _ => None,
+        }
+    }
+
+    pub fn is_expired(&self, now: Timestamp) -> bool {
+        match &self.market {
+            Some(market) => market.is_expired(now, self.expiry_futures_info()),
+            None => self.expiry_futures_info().is_some_and(|info| info.is_expired(now)),
+        }
+    }
+
+    pub fn time_to_expiry(&self, now: Timestamp) -> Option<u64> {
+        self.expiry_futures_info().map(|info| info.time_to_expiry(now))
+    }
+}
+
 #[allow(non_snake_case)]
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 pub struct DerivativeMarket {
@@ -70,6 +191,25 @@ pub struct DerivativeMarket {
     pub min_quantity_tick_size: FPDecimal,
 }
 
+impl DerivativeMarket {
+    pub fn is_expiry_futures(&self) -> bool {
+        !self.isPerpetual
+    }
+
+    /// Perpetual markets only expire through their status, expiry futures also once `now` passes the expiration timestamp
+    pub fn is_expired(&self, now: Timestamp, expiry_info: Option<&ExpiryFuturesMarketInfo>) -> bool {
+        self.status == MarketStatus::Expired || self.expiry_info(expiry_info).is_some_and(|info| info.is_expired(now))
+    }
+
+    pub fn time_to_expiry(&self, now: Timestamp, expiry_info: Option<&ExpiryFuturesMarketInfo>) -> Option<u64> {
+        self.expiry_info(expiry_info).map(|info| info.time_to_expiry(now))
+    }
+
+    fn expiry_info<'a>(&self, expiry_info: Option<&'a ExpiryFuturesMarketInfo>) -> Option<&'a ExpiryFuturesMarketInfo> {
+        expiry_info.filter(|_| self.is_expiry_futures())
+    }
+}
+
 impl GenericMarket for DerivativeMarket {
     fn get_ticker(&self) -> &str {
         &self.ticker
@@ -103,3 +243,120 @@ impl GenericMarket for DerivativeMarket {
         self.min_quantity_tick_size
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::Timestamp;
+    use injective_math::FPDecimal;
+
+    use crate::exchange::derivative::Position;
+    use crate::exchange::derivative_market::{
+        ExpiryFuturesMarketInfo, FullDerivativeMarket, FullDerivativeMarketInfo, PerpetualMarketFunding, PerpetualMarketInfo, PerpetualMarketState,
+    };
+    use crate::MarketId;
+
+    const MARKET_ID: &str = "0x01edfab47f124748dc89998eb33144af734484ba07099014594321729a0ca16b";
+
+    fn expiry_info() -> ExpiryFuturesMarketInfo {
+        ExpiryFuturesMarketInfo {
+            market_id: MarketId::unchecked(MARKET_ID),
+            expiration_timestamp: 1_700_000_000,
+            twap_start_timestamp: 1_699_999_400,
+            expiration_twap_start_price_cumulative: FPDecimal::ZERO,
+            settlement_price: FPDecimal::ZERO,
+        }
+    }
+
+    fn perpetual_state(cumulative_price: &str) -> PerpetualMarketState {
+        PerpetualMarketState {
+            market_info: PerpetualMarketInfo {
+                market_id: MarketId::unchecked(MARKET_ID),
+                hourly_funding_rate_cap: FPDecimal::must_from_str("0.000625"),
+                hourly_interest_rate: FPDecimal::must_from_str("0.00001"),
+                next_funding_timestamp: 7_200,
+                funding_interval: 3_600,
+            },
+            funding_info: PerpetualMarketFunding {
+                cumulative_funding: FPDecimal::must_from_str("0.5"),
+                cumulative_price: FPDecimal::must_from_str(cumulative_price),
+                last_timestamp: 5_400,
+            },
+        }
+    }
+
+    #[test]
+    fn full_derivative_market_deserializes_perpetual_info() {
+        let json = format!(
+            r#"{{"market":null,"info":{{"perpetual_info":{{"market_info":{{"market_id":"{MARKET_ID}","hourly_funding_rate_cap":"0.000625","hourly_interest_rate":"0.00000416666","next_funding_timestamp":1700003600,"funding_interval":3600}},"funding_info":{{"cumulative_funding":"0","cumulative_price":"0","last_timestamp":1700000000}}}}}},"mark_price":"10"}}"#
+        );
+        let market: FullDerivativeMarket = serde_json_wasm::from_str(&json).unwrap();
+
+        assert!(market.expiry_futures_info().is_none());
+        assert_eq!(market.perpetual_info().unwrap().market_info.funding_interval, 3600);
+    }
+
+    #[test]
+    fn full_derivative_market_deserializes_futures_info() {
+        let json = format!(
+            r#"{{"market":null,"info":{{"futures_info":{{"market_id":"{MARKET_ID}","expiration_timestamp":1700000000,"twap_start_timestamp":1699999400,"expiration_twap_start_price_cumulative":"0","settlement_price":"0"}}}},"mark_price":"10"}}"#
+        );
+        let market: FullDerivativeMarket = serde_json_wasm::from_str(&json).unwrap();
+
+        assert!(market.perpetual_info().is_none());
+        assert_eq!(market.info, Some(FullDerivativeMarketInfo::FuturesInfo(expiry_info())));
+        assert_eq!(market.time_to_expiry(Timestamp::from_seconds(1_699_999_000)), Some(1_000));
+    }
+
+    #[test]
+    fn expiry_futures_market_info_expiry() {
+        let info = expiry_info();
+
+        assert!(!info.is_expired(Timestamp::from_seconds(1_699_999_999)));
+        assert!(info.is_expired(Timestamp::from_seconds(1_700_000_000)));
+        assert_eq!(info.time_to_expiry(Timestamp::from_seconds(1_699_999_990)), 10);
+        assert_eq!(info.time_to_expiry(Timestamp::from_seconds(1_700_000_010)), 0);
+        assert!(!info.is_settled());
+    }
+
+    #[test]
+    fn estimated_funding_rate_adds_interest_to_premium_twap() {
+        // 4.32 accumulated over 1800s is a 0.0001 hourly premium
+        let state = perpetual_state("4.32");
+
+        assert_eq!(
+            state.funding_info.premium_twap(state.market_info.funding_interval_start()),
+            FPDecimal::must_from_str("0.0001")
+        );
+        assert_eq!(state.estimate_funding_rate(), FPDecimal::must_from_str("0.00011"));
+        assert_eq!(
+            state
+                .market_info
+                .estimate_next_cumulative_funding(&state.funding_info, FPDecimal::must_from_str("10")),
+            FPDecimal::must_from_str("0.5011")
+        );
+    }
+
+    #[test]
+    fn estimated_funding_rate_is_clamped_to_cap() {
+        assert_eq!(perpetual_state("43.2").estimate_funding_rate(), FPDecimal::must_from_str("0.000625"));
+        assert_eq!(perpetual_state("-43.2").estimate_funding_rate(), FPDecimal::must_from_str("-0.000625"));
+    }
+
+    #[test]
+    fn projected_funding_payment_is_paid_by_longs_to_shorts() {
+        let state = perpetual_state("4.32");
+        let mut position = Position {
+            isLong: true,
+            quantity: FPDecimal::TWO,
+            entry_price: FPDecimal::must_from_str("10"),
+            margin: FPDecimal::must_from_str("10"),
+            cumulative_funding_entry: FPDecimal::must_from_str("0.5"),
+        };
+        let mark_price = FPDecimal::must_from_str("10");
+
+        assert_eq!(state.project_funding_payment(&position, mark_price), FPDecimal::must_from_str("-0.0022"));
+
+        position.isLong = false;
+        assert_eq!(state.project_funding_payment(&position, mark_price), FPDecimal::must_from_str("0.0022"));
+    }
+}
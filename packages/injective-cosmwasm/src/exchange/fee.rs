@@ -0,0 +1,216 @@
+use injective_math::FPDecimal;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::exchange::{
+    derivative::DerivativeOrder,
+    derivative_market::DerivativeMarket,
+    market::GenericMarket,
+    order::{GenericOrder, OrderType},
+    spot::SpotOrder,
+    spot_market::SpotMarket,
+    types::Params,
+};
+
+/// Whether an order's fill rests on the book or takes liquidity from it
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Liquidity {
+    Maker,
+    Taker,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct TradeFee {
+    pub fee_rate: FPDecimal,
+    /// Fee charged on the notional, negative for a maker rebate
+    pub fee: FPDecimal,
+    /// Part of the fee paid out to the order's fee recipient
+    pub relayer_fee: FPDecimal,
+}
+
+impl TradeFee {
+    pub fn is_rebate(&self) -> bool {
+        self.fee.is_negative()
+    }
+
+    /// Part of the fee kept by the exchange
+    pub fn protocol_fee(&self) -> FPDecimal {
+        self.fee - self.relayer_fee
+    }
+}
+
+/// Combines a market's fee rates, the relayer fee share and the atomic execution fee multiplier
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct FeeCalculator {
+    pub maker_fee_rate: FPDecimal,
+    pub taker_fee_rate: FPDecimal,
+    pub relayer_fee_share_rate: FPDecimal,
+    pub atomic_fee_multiplier: FPDecimal,
+}
+
+impl FeeCalculator {
+    pub fn new(market: &impl GenericMarket, relayer_fee_share_rate: FPDecimal) -> Self {
+        FeeCalculator {
+            maker_fee_rate: market.get_maker_fee_rate(),
+            taker_fee_rate: market.get_taker_fee_rate(),
+            relayer_fee_share_rate,
+            atomic_fee_multiplier: FPDecimal::ONE,
+        }
+    }
+
+    /// Uses `Params.spot_atomic_market_order_fee_multiplier` for atomic orders
+    pub fn for_spot_market(market: &SpotMarket, params: &Params) -> Self {
+        FeeCalculator::new(market, market.relayer_fee_share_rate).with_atomic_fee_multiplier(params.spot_atomic_market_order_fee_multiplier)
+    }
+
+    /// Uses `Params.derivative_atomic_market_order_fee_multiplier` for atomic orders
+    pub fn for_derivative_market(market: &DerivativeMarket, params: &Params) -> Self {
+        FeeCalculator::new(market, params.relayer_fee_share_rate).with_atomic_fee_multiplier(params.derivative_atomic_market_order_fee_multiplier)
+    }
+
+    /// Overrides the atomic execution fee multiplier, e.g. with the one returned by `query_market_atomic_execution_fee_multiplier`
+    pub fn with_atomic_fee_multiplier(mut self, atomic_fee_multiplier: FPDecimal) -> Self {
+        self.atomic_fee_multiplier = atomic_fee_multiplier;
+        self
+    }
+
+    /// Post-only orders always pay the maker rate and atomic orders the taker rate times the atomic multiplier,
+    /// `liquidity` only matters for regular orders
+    pub fn fee_rate(&self, order_type: &OrderType, liquidity: Liquidity) -> FPDecimal {
+        match order_type {
+            OrderType::BuyPo | OrderType::SellPo => self.maker_fee_rate,
+            OrderType::BuyAtomic | OrderType::SellAtomic => self.taker_fee_rate * self.atomic_fee_multiplier,
+            _ => match liquidity {
+                Liquidity::Maker => self.maker_fee_rate,
+                Liquidity::Taker => self.taker_fee_rate,
+            },
+        }
+    }
+
+    pub fn trade_fee(&self, order_type: &OrderType, liquidity: Liquidity, notional: FPDecimal, has_fee_recipient: bool) -> TradeFee {
+        let fee_rate = self.fee_rate(order_type, liquidity);
+        let fee = notional * fee_rate;
+
+        // rebates are paid by the exchange, relayers only get a share of positive fees
+        let relayer_fee = if has_fee_recipient && fee > FPDecimal::ZERO {
+            fee * self.relayer_fee_share_rate
+        } else {
+            FPDecimal::ZERO
+        };
+
+        TradeFee { fee_rate, fee, relayer_fee }
+    }
+
+    /// Fee for filling the whole order at its price
+    pub fn order_fee(&self, order: &impl GenericOrder, liquidity: Liquidity) -> TradeFee {
+        let order_info = order.get_order_info();
+
+        self.trade_fee(
+            order.get_order_type(),
+            liquidity,
+            order_info.price * order_info.quantity,
+            order_info.fee_recipient.is_some(),
+        )
+    }
+
+    /// Quote funds the chain holds when the order is placed: notional plus the worst case fee for buys, nothing for sells
+    /// which lock base funds instead
+    pub fn spot_order_quote_hold(&self, order: &SpotOrder) -> FPDecimal {
+        if !order.is_buy() {
+            return FPDecimal::ZERO;
+        }
+
+        let notional = order.get_val();
+        notional + notional * self.hold_fee_rate(&order.order_type)
+    }
+
+    /// Quote funds the chain holds when the order is placed: margin plus the worst case fee
+    pub fn derivative_order_quote_hold(&self, order: &DerivativeOrder) -> FPDecimal {
+        order.margin + order.get_val() * self.hold_fee_rate(&order.order_type)
+    }
+
+    fn hold_fee_rate(&self, order_type: &OrderType) -> FPDecimal {
+        self.fee_rate(order_type, Liquidity::Taker).max(FPDecimal::ZERO)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::Addr;
+    use injective_math::FPDecimal;
+
+    use crate::exchange::{
+        fee::{FeeCalculator, Liquidity},
+        market::MarketStatus,
+        order::OrderType,
+        spot::SpotOrder,
+        spot_market::SpotMarket,
+        types::{MarketId, SubaccountId},
+    };
+
+    fn calculator() -> FeeCalculator {
+        let market = SpotMarket {
+            ticker: "INJ/USDT".to_string(),
+            base_denom: "inj".to_string(),
+            quote_denom: "usdt".to_string(),
+            maker_fee_rate: FPDecimal::must_from_str("-0.0001"),
+            taker_fee_rate: FPDecimal::must_from_str("0.001"),
+            relayer_fee_share_rate: FPDecimal::must_from_str("0.4"),
+            market_id: MarketId::unchecked("0x0611780ba69656949525013d947713300f56c37b6175e02f26bffa495c3208fe"),
+            status: MarketStatus::Active,
+            min_price_tick_size: FPDecimal::must_from_str("0.01"),
+            min_quantity_tick_size: FPDecimal::must_from_str("0.1"),
+        };
+
+        FeeCalculator::new(&market, market.relayer_fee_share_rate).with_atomic_fee_multiplier(FPDecimal::must_from_str("2.5"))
+    }
+
+    fn order(order_type: OrderType, fee_recipient: Option<Addr>) -> SpotOrder {
+        SpotOrder::new(
+            FPDecimal::must_from_str("10"),
+            FPDecimal::must_from_str("100"),
+            order_type,
+            &MarketId::unchecked("0x0611780ba69656949525013d947713300f56c37b6175e02f26bffa495c3208fe"),
+            SubaccountId::unchecked("0xaf79152ac5df276d9a8e1e2e22822f9713474902000000000000000000000000"),
+            fee_recipient,
+            None,
+        )
+    }
+
+    #[test]
+    fn taker_fee_is_shared_with_fee_recipient() {
+        let fee = calculator().order_fee(&order(OrderType::Buy, Some(Addr::unchecked("inj1relayer"))), Liquidity::Taker);
+
+        assert_eq!(fee.fee, FPDecimal::ONE);
+        assert_eq!(fee.relayer_fee, FPDecimal::must_from_str("0.4"));
+        assert_eq!(fee.protocol_fee(), FPDecimal::must_from_str("0.6"));
+    }
+
+    #[test]
+    fn negative_maker_fee_is_a_rebate_without_relayer_share() {
+        let calculator = calculator();
+        let fee = calculator.order_fee(&order(OrderType::SellPo, Some(Addr::unchecked("inj1relayer"))), Liquidity::Taker);
+
+        assert!(fee.is_rebate());
+        assert_eq!(fee.fee, FPDecimal::must_from_str("-0.1"));
+        assert_eq!(fee.relayer_fee, FPDecimal::ZERO);
+        assert_eq!(
+            calculator.spot_order_quote_hold(&order(OrderType::BuyPo, None)),
+            FPDecimal::must_from_str("1000")
+        );
+    }
+
+    #[test]
+    fn atomic_buy_holds_notional_plus_multiplied_taker_fee() {
+        let calculator = calculator();
+        let atomic_buy = order(OrderType::BuyAtomic, None);
+
+        assert_eq!(
+            calculator.order_fee(&atomic_buy, Liquidity::Maker).fee_rate,
+            FPDecimal::must_from_str("0.0025")
+        );
+        assert_eq!(calculator.spot_order_quote_hold(&atomic_buy), FPDecimal::must_from_str("1002.5"));
+        assert_eq!(calculator.spot_order_quote_hold(&order(OrderType::SellAtomic, None)), FPDecimal::ZERO);
+    }
+}
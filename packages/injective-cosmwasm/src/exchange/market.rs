@@ -2,7 +2,7 @@ use injective_math::FPDecimal;
 use schemars::JsonSchema;
 use serde_repr::{Deserialize_repr, Serialize_repr};
 
-use crate::MarketId;
+use crate::{error::InjectiveError, MarketId};
 
 #[derive(Serialize_repr, Deserialize_repr, Default, Clone, Debug, PartialEq, Eq, JsonSchema, Copy)]
 #[repr(i32)]
@@ -24,4 +24,14 @@ pub trait GenericMarket {
     fn get_status(&self) -> MarketStatus;
     fn get_min_price_tick_size(&self) -> FPDecimal;
     fn min_quantity_tick_size(&self) -> FPDecimal;
+
+    fn ensure_active(&self) -> Result<(), InjectiveError> {
+        match self.get_status() {
+            MarketStatus::Active => Ok(()),
+            status => Err(InjectiveError::MarketNotActive {
+                market_id: self.get_market_id().clone(),
+                status,
+            }),
+        }
+    }
 }
@@ -1,8 +1,15 @@
+pub mod account_snapshot;
+pub mod binary_options_market;
 pub mod cancel;
+pub mod cid;
 pub mod derivative;
 pub mod derivative_market;
+pub mod fee;
 pub mod market;
 pub mod order;
+pub mod order_builder;
+pub mod order_hash;
+pub mod orderbook;
 pub mod privileged_action;
 pub mod response;
 pub mod spot;
@@ -32,18 +32,45 @@ pub enum OrderType {
     SellAtomic = 10,
 }
 
+/// Identifies an order to cancel, either by `order_hash` or by `cid`
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 pub struct OrderData {
     pub market_id: MarketId,
     pub subaccount_id: SubaccountId,
+    #[serde(default)]
     pub order_hash: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cid: Option<String>,
+}
+
+impl OrderData {
+    pub fn new(market_id: MarketId, subaccount_id: SubaccountId, order_hash: String) -> Self {
+        OrderData {
+            market_id,
+            subaccount_id,
+            order_hash,
+            cid: None,
+        }
+    }
+
+    pub fn from_cid(market_id: MarketId, subaccount_id: SubaccountId, cid: String) -> Self {
+        OrderData {
+            market_id,
+            subaccount_id,
+            order_hash: "".to_string(),
+            cid: Some(cid),
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 pub struct ShortOrderData {
     pub market_id: MarketId,
     pub subaccount_id: ShortSubaccountId,
+    #[serde(default)]
     pub order_hash: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cid: Option<String>,
 }
 
 impl From<OrderData> for ShortOrderData {
@@ -52,6 +79,7 @@ impl From<OrderData> for ShortOrderData {
             market_id: order.market_id,
             subaccount_id: order.subaccount_id.into(),
             order_hash: order.order_hash,
+            cid: order.cid,
         }
     }
 }
@@ -0,0 +1,282 @@
+use cosmwasm_std::Addr;
+use injective_math::{
+    utils::{round_to_min_tick, round_to_nearest_tick, round_up_to_min_tick},
+    FPDecimal,
+};
+
+use crate::error::InjectiveError;
+use crate::exchange::{
+    derivative::DerivativeOrder,
+    market::GenericMarket,
+    order::{OrderInfo, OrderType},
+    spot::SpotOrder,
+    types::SubaccountId,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RoundingDirection {
+    Down,
+    Up,
+    Nearest,
+}
+
+impl RoundingDirection {
+    pub fn apply(&self, value: FPDecimal, min_tick: FPDecimal) -> FPDecimal {
+        match self {
+            RoundingDirection::Down => round_to_min_tick(value, min_tick),
+            RoundingDirection::Up => round_up_to_min_tick(value, min_tick),
+            RoundingDirection::Nearest => round_to_nearest_tick(value, min_tick),
+        }
+    }
+}
+
+/// Builds spot and derivative orders whose price and quantity are valid for the given market.
+///
+/// By default buy prices are rounded down and sell prices up, so that rounding never makes an order
+/// more aggressive than requested, while quantities are always rounded down.
+pub struct OrderBuilder<'a, M: GenericMarket> {
+    market: &'a M,
+    subaccount_id: SubaccountId,
+    order_type: OrderType,
+    price: FPDecimal,
+    quantity: FPDecimal,
+    trigger_price: Option<FPDecimal>,
+    fee_recipient: Option<Addr>,
+    cid: Option<String>,
+    price_rounding: Option<RoundingDirection>,
+    quantity_rounding: RoundingDirection,
+}
+
+impl<'a, M: GenericMarket> OrderBuilder<'a, M> {
+    pub fn new(market: &'a M, subaccount_id: SubaccountId, order_type: OrderType, price: FPDecimal, quantity: FPDecimal) -> Self {
+        OrderBuilder {
+            market,
+            subaccount_id,
+            order_type,
+            price,
+            quantity,
+            trigger_price: None,
+            fee_recipient: None,
+            cid: None,
+            price_rounding: None,
+            quantity_rounding: RoundingDirection::Down,
+        }
+    }
+
+    pub fn trigger_price(mut self, trigger_price: FPDecimal) -> Self {
+        self.trigger_price = Some(trigger_price);
+        self
+    }
+
+    pub fn fee_recipient(mut self, fee_recipient: Addr) -> Self {
+        self.fee_recipient = Some(fee_recipient);
+        self
+    }
+
+    pub fn cid(mut self, cid: impl Into<String>) -> Self {
+        self.cid = Some(cid.into());
+        self
+    }
+
+    pub fn price_rounding(mut self, direction: RoundingDirection) -> Self {
+        self.price_rounding = Some(direction);
+        self
+    }
+
+    pub fn quantity_rounding(mut self, direction: RoundingDirection) -> Self {
+        self.quantity_rounding = direction;
+        self
+    }
+
+    pub fn build_spot(self) -> Result<SpotOrder, InjectiveError> {
+        let (order_info, trigger_price) = self.validated_order_info()?;
+
+        Ok(SpotOrder {
+            market_id: self.market.get_market_id().clone(),
+            order_info,
+            order_type: self.order_type,
+            trigger_price,
+        })
+    }
+
+    /// Zero margin builds a reduce-only order
+    pub fn build_derivative(self, margin: FPDecimal) -> Result<DerivativeOrder, InjectiveError> {
+        if margin.is_negative() {
+            return Err(InjectiveError::InvalidMargin { margin });
+        }
+
+        let (order_info, trigger_price) = self.validated_order_info()?;
+
+        Ok(DerivativeOrder {
+            market_id: self.market.get_market_id().clone(),
+            order_info,
+            order_type: self.order_type,
+            margin,
+            trigger_price,
+        })
+    }
+
+    fn validated_order_info(&self) -> Result<(OrderInfo, Option<FPDecimal>), InjectiveError> {
+        self.market.ensure_active()?;
+
+        let is_buy = match self.order_type {
+            OrderType::Buy | OrderType::StopBuy | OrderType::TakeBuy | OrderType::BuyPo | OrderType::BuyAtomic => true,
+            OrderType::Sell | OrderType::StopSell | OrderType::TakeSell | OrderType::SellPo | OrderType::SellAtomic => false,
+            OrderType::Undefined => {
+                return Err(InjectiveError::InvalidOrderType {
+                    order_type: self.order_type.clone(),
+                })
+            }
+        };
+
+        let price_rounding = self
+            .price_rounding
+            .unwrap_or(if is_buy { RoundingDirection::Down } else { RoundingDirection::Up });
+
+        let price = self.round_price(self.price, price_rounding)?;
+        let trigger_price = self.trigger_price.map(|p| self.round_price(p, price_rounding)).transpose()?;
+
+        if self.quantity <= FPDecimal::ZERO {
+            return Err(InjectiveError::InvalidQuantity { quantity: self.quantity });
+        }
+
+        let min_quantity_tick_size = self.market.min_quantity_tick_size();
+        let quantity = self.quantity_rounding.apply(self.quantity, min_quantity_tick_size);
+        if quantity.is_zero() {
+            return Err(InjectiveError::QuantityBelowMinTick {
+                quantity: self.quantity,
+                min_quantity_tick_size,
+            });
+        }
+
+        let order_info = OrderInfo {
+            subaccount_id: self.subaccount_id.clone(),
+            fee_recipient: self.fee_recipient.clone(),
+            price,
+            quantity,
+            cid: self.cid.clone(),
+        };
+
+        Ok((order_info, trigger_price))
+    }
+
+    fn round_price(&self, price: FPDecimal, direction: RoundingDirection) -> Result<FPDecimal, InjectiveError> {
+        if price <= FPDecimal::ZERO {
+            return Err(InjectiveError::InvalidPrice { price });
+        }
+
+        let min_price_tick_size = self.market.get_min_price_tick_size();
+        let rounded = direction.apply(price, min_price_tick_size);
+        if rounded.is_zero() {
+            return Err(InjectiveError::PriceBelowMinTick { price, min_price_tick_size });
+        }
+
+        Ok(rounded)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use injective_math::FPDecimal;
+
+    use crate::exchange::{
+        market::MarketStatus,
+        order::OrderType,
+        order_builder::{OrderBuilder, RoundingDirection},
+    };
+    use crate::InjectiveError;
+    use crate::{MarketId, SpotMarket, SubaccountId};
+
+    fn spot_market(status: MarketStatus) -> SpotMarket {
+        SpotMarket {
+            ticker: "INJ/USDT".to_string(),
+            base_denom: "inj".to_string(),
+            quote_denom: "peggy0x87aB3B4C8661e07D6372361211B96ed4Dc36B1B5".to_string(),
+            maker_fee_rate: FPDecimal::must_from_str("-0.0001"),
+            taker_fee_rate: FPDecimal::must_from_str("0.001"),
+            relayer_fee_share_rate: FPDecimal::must_from_str("0.4"),
+            market_id: MarketId::unchecked("0x0611780ba69656949525013d947713300f56c37b6175e02f26bffa495c3208fe"),
+            status,
+            min_price_tick_size: FPDecimal::must_from_str("0.01"),
+            min_quantity_tick_size: FPDecimal::must_from_str("0.1"),
+        }
+    }
+
+    fn subaccount_id() -> SubaccountId {
+        SubaccountId::unchecked("0x427aee334987c52fa7b567b2662bdbb68614e48c000000000000000000000001")
+    }
+
+    #[test]
+    fn rounds_price_away_from_aggressive_side() {
+        let market = spot_market(MarketStatus::Active);
+        let price = FPDecimal::must_from_str("10.127");
+        let quantity = FPDecimal::must_from_str("1.37");
+
+        let buy = OrderBuilder::new(&market, subaccount_id(), OrderType::Buy, price, quantity)
+            .build_spot()
+            .unwrap();
+        assert_eq!(buy.get_price(), FPDecimal::must_from_str("10.12"));
+        assert_eq!(buy.get_quantity(), FPDecimal::must_from_str("1.3"));
+
+        let sell = OrderBuilder::new(&market, subaccount_id(), OrderType::SellPo, price, quantity)
+            .build_spot()
+            .unwrap();
+        assert_eq!(sell.get_price(), FPDecimal::must_from_str("10.13"));
+
+        let nearest = OrderBuilder::new(&market, subaccount_id(), OrderType::Buy, price, quantity)
+            .price_rounding(RoundingDirection::Nearest)
+            .quantity_rounding(RoundingDirection::Up)
+            .build_derivative(FPDecimal::ZERO)
+            .unwrap();
+        assert_eq!(nearest.get_price(), FPDecimal::must_from_str("10.13"));
+        assert_eq!(nearest.get_quantity(), FPDecimal::must_from_str("1.4"));
+        assert!(nearest.is_reduce_only());
+    }
+
+    #[test]
+    fn rejects_orders_the_chain_would_reject() {
+        let paused = spot_market(MarketStatus::Paused);
+        let err = OrderBuilder::new(&paused, subaccount_id(), OrderType::Buy, FPDecimal::ONE, FPDecimal::ONE)
+            .build_spot()
+            .unwrap_err();
+        assert!(matches!(err, InjectiveError::MarketNotActive { .. }));
+
+        let market = spot_market(MarketStatus::Active);
+        let err = OrderBuilder::new(
+            &market,
+            subaccount_id(),
+            OrderType::Buy,
+            FPDecimal::must_from_str("0.001"),
+            FPDecimal::ONE,
+        )
+        .build_spot()
+        .unwrap_err();
+        assert!(matches!(err, InjectiveError::PriceBelowMinTick { .. }));
+
+        let err = OrderBuilder::new(
+            &market,
+            subaccount_id(),
+            OrderType::Sell,
+            FPDecimal::ONE,
+            FPDecimal::must_from_str("0.05"),
+        )
+        .build_spot()
+        .unwrap_err();
+        assert!(matches!(err, InjectiveError::QuantityBelowMinTick { .. }));
+
+        let err = OrderBuilder::new(&market, subaccount_id(), OrderType::Undefined, FPDecimal::ONE, FPDecimal::ONE)
+            .build_spot()
+            .unwrap_err();
+        assert!(matches!(err, InjectiveError::InvalidOrderType { .. }));
+
+        let err = OrderBuilder::new(&market, subaccount_id(), OrderType::Buy, FPDecimal::ONE, FPDecimal::ONE)
+            .build_derivative(FPDecimal::must_from_str("-1"))
+            .unwrap_err();
+        assert_eq!(
+            err,
+            InjectiveError::InvalidMargin {
+                margin: FPDecimal::must_from_str("-1")
+            }
+        );
+    }
+}
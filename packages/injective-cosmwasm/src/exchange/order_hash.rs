@@ -0,0 +1,316 @@
+use cosmwasm_std::Addr;
+use injective_math::FPDecimal;
+use tiny_keccak::Keccak;
+
+use crate::error::InjectiveError;
+use crate::exchange::{
+    derivative::DerivativeOrder,
+    order::{OrderInfo, OrderType},
+    response::SubaccountTradeNonceResponse,
+    spot::SpotOrder,
+    types::Hash,
+};
+
+const ORDER_INFO_TYPE: &str = "OrderInfo(string SubaccountId,string FeeRecipient,string Price,string Quantity)";
+const SPOT_ORDER_TYPE: &str = "SpotOrder(string MarketId,OrderInfo OrderInfo,string Salt,string OrderType,string TriggerPrice)";
+const DERIVATIVE_ORDER_TYPE: &str =
+    "DerivativeOrder(string MarketId,OrderInfo OrderInfo,string OrderType,string Margin,string TriggerPrice,string Salt)";
+const DOMAIN_TYPE: &str = "EIP712Domain(string name,string version,uint256 chainId,address verifyingContract,bytes32 salt)";
+
+const DOMAIN_NAME: &str = "Injective Protocol";
+const DOMAIN_VERSION: &str = "2.0.0";
+const DOMAIN_CHAIN_ID: u64 = 888;
+const DOMAIN_VERIFYING_CONTRACT: [u8; 20] = [0xcc; 20];
+
+/// Hash the exchange module assigns to a spot order sent by `sender` with the given subaccount trade nonce.
+/// The chain increments the nonce before hashing, so a new order uses the queried nonce plus one, see [`OrderHashGenerator`].
+/// Orders without a fee recipient are hashed with `sender` as fee recipient, which the chain sets before hashing.
+pub fn compute_spot_order_hash(order: &SpotOrder, sender: &Addr, nonce: u32) -> Hash {
+    let mut data = type_hash(&[SPOT_ORDER_TYPE, ORDER_INFO_TYPE]).to_vec();
+    data.extend_from_slice(&keccak256(order.market_id.as_str().as_bytes()));
+    data.extend_from_slice(&order_info_hash(&order.order_info, sender));
+    data.extend_from_slice(&keccak256(nonce.to_string().as_bytes()));
+    data.extend_from_slice(&keccak256(order_type_string(&order.order_type).as_bytes()));
+    data.extend_from_slice(&keccak256(trigger_price_string(order.trigger_price).as_bytes()));
+
+    typed_data_hash(keccak256(&data))
+}
+
+/// Hash the exchange module assigns to a derivative or binary options order sent by `sender` with the given subaccount trade nonce,
+/// see [`compute_spot_order_hash`]
+pub fn compute_derivative_order_hash(order: &DerivativeOrder, sender: &Addr, nonce: u32) -> Hash {
+    let mut data = type_hash(&[DERIVATIVE_ORDER_TYPE, ORDER_INFO_TYPE]).to_vec();
+    data.extend_from_slice(&keccak256(order.market_id.as_str().as_bytes()));
+    data.extend_from_slice(&order_info_hash(&order.order_info, sender));
+    data.extend_from_slice(&keccak256(order_type_string(&order.order_type).as_bytes()));
+    data.extend_from_slice(&keccak256(dec_string(order.margin).as_bytes()));
+    data.extend_from_slice(&keccak256(trigger_price_string(order.trigger_price).as_bytes()));
+    data.extend_from_slice(&keccak256(nonce.to_string().as_bytes()));
+
+    typed_data_hash(keccak256(&data))
+}
+
+/// Predicts the hashes of orders a subaccount creates, in the order they are created.
+/// Every created order increments the nonce, including market orders, while cancellations leave it unchanged.
+pub struct OrderHashGenerator {
+    sender: Addr,
+    nonce: u32,
+}
+
+impl OrderHashGenerator {
+    /// `sender` sends the order messages, usually the contract itself, and `nonce` is the current subaccount trade nonce,
+    /// as returned by `query_subaccount_trade_nonce`
+    pub fn new(sender: Addr, nonce: u32) -> Self {
+        OrderHashGenerator { sender, nonce }
+    }
+
+    pub fn from_response(sender: Addr, response: &SubaccountTradeNonceResponse) -> Self {
+        OrderHashGenerator::new(sender, response.nonce)
+    }
+
+    /// Fails without advancing the nonce once it reached `u32::MAX`, the chain cannot assign a hash past it
+    pub fn next_spot_order_hash(&mut self, order: &SpotOrder) -> Result<Hash, InjectiveError> {
+        let nonce = self.next_nonce()?;
+        Ok(compute_spot_order_hash(order, &self.sender, nonce))
+    }
+
+    /// Fails without advancing the nonce once it reached `u32::MAX`, see [`OrderHashGenerator::next_spot_order_hash`]
+    pub fn next_derivative_order_hash(&mut self, order: &DerivativeOrder) -> Result<Hash, InjectiveError> {
+        let nonce = self.next_nonce()?;
+        Ok(compute_derivative_order_hash(order, &self.sender, nonce))
+    }
+
+    pub fn nonce(&self) -> u32 {
+        self.nonce
+    }
+
+    fn next_nonce(&mut self) -> Result<u32, InjectiveError> {
+        self.nonce = self.nonce.checked_add(1).ok_or(InjectiveError::TradeNonceExhausted)?;
+        Ok(self.nonce)
+    }
+}
+
+// the chain defaults an empty fee recipient to the message sender before computing the hash
+fn order_info_hash(order_info: &OrderInfo, sender: &Addr) -> [u8; 32] {
+    let fee_recipient = order_info.fee_recipient.as_ref().unwrap_or(sender);
+
+    let mut data = type_hash(&[ORDER_INFO_TYPE]).to_vec();
+    data.extend_from_slice(&keccak256(order_info.subaccount_id.as_str().as_bytes()));
+    data.extend_from_slice(&keccak256(fee_recipient.as_bytes()));
+    data.extend_from_slice(&keccak256(dec_string(order_info.price).as_bytes()));
+    data.extend_from_slice(&keccak256(dec_string(order_info.quantity).as_bytes()));
+
+    keccak256(&data)
+}
+
+fn domain_separator() -> [u8; 32] {
+    let mut chain_id = [0u8; 32];
+    chain_id[24..].copy_from_slice(&DOMAIN_CHAIN_ID.to_be_bytes());
+    let mut verifying_contract = [0u8; 32];
+    verifying_contract[12..].copy_from_slice(&DOMAIN_VERIFYING_CONTRACT);
+
+    let mut data = type_hash(&[DOMAIN_TYPE]).to_vec();
+    data.extend_from_slice(&keccak256(DOMAIN_NAME.as_bytes()));
+    data.extend_from_slice(&keccak256(DOMAIN_VERSION.as_bytes()));
+    data.extend_from_slice(&chain_id);
+    data.extend_from_slice(&verifying_contract);
+    data.extend_from_slice(&[0u8; 32]);
+
+    keccak256(&data)
+}
+
+fn typed_data_hash(struct_hash: [u8; 32]) -> Hash {
+    Hash::new(eip712_digest(domain_separator(), struct_hash))
+}
+
+fn eip712_digest(domain_separator: [u8; 32], struct_hash: [u8; 32]) -> [u8; 32] {
+    let mut data = vec![0x19, 0x01];
+    data.extend_from_slice(&domain_separator);
+    data.extend_from_slice(&struct_hash);
+
+    keccak256(&data)
+}
+
+fn type_hash(types: &[&str]) -> [u8; 32] {
+    keccak256(types.concat().as_bytes())
+}
+
+// the chain converts the order type enum with Go's string(), which yields the character with that code point
+fn order_type_string(order_type: &OrderType) -> String {
+    char::from(order_type.clone() as u8).to_string()
+}
+
+fn trigger_price_string(trigger_price: Option<FPDecimal>) -> String {
+    trigger_price.map(dec_string).unwrap_or_default()
+}
+
+/// Formats like the chain's `sdk.Dec`, which always prints 18 decimals
+fn dec_string(value: FPDecimal) -> String {
+    let sign = if value.is_negative() { "-" } else { "" };
+    let integer = value.num / FPDecimal::ONE.num;
+    let fraction = (value.num % FPDecimal::ONE.num).to_string();
+
+    format!("{}{}.{:0>width$}", sign, integer, fraction, width = FPDecimal::DIGITS)
+}
+
+fn keccak256(data: &[u8]) -> [u8; 32] {
+    let mut hasher = Keccak::new_keccak256();
+    hasher.update(data);
+    let mut res = [0u8; 32];
+    hasher.finalize(&mut res);
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::Addr;
+    use injective_math::FPDecimal;
+
+    use crate::error::InjectiveError;
+    use crate::exchange::{
+        derivative::DerivativeOrder,
+        order::OrderType,
+        order_hash::{compute_derivative_order_hash, compute_spot_order_hash, dec_string, eip712_digest, keccak256, type_hash, OrderHashGenerator},
+        spot::SpotOrder,
+        types::{MarketId, SubaccountId},
+    };
+
+    const MARKET_ID: &str = "0x0611780ba69656949525013d947713300f56c37b6175e02f26bffa495c3208fe";
+    const SUBACCOUNT_ID: &str = "0xaf79152ac5df276d9a8e1e2e22822f9713474902000000000000000000000000";
+
+    fn address_word(address: &str) -> [u8; 32] {
+        let mut word = [0u8; 32];
+        hex::decode_to_slice(address.trim_start_matches("0x"), &mut word[12..]).unwrap();
+        word
+    }
+
+    // the `Mail` example from the EIP-712 specification, checking the type hashing and encoding the order hashes are built on
+    #[test]
+    fn eip712_specification_example() {
+        let person_type = "Person(string name,address wallet)";
+        let person_hash = |name: &str, wallet: &str| {
+            let mut data = type_hash(&[person_type]).to_vec();
+            data.extend_from_slice(&keccak256(name.as_bytes()));
+            data.extend_from_slice(&address_word(wallet));
+            keccak256(&data)
+        };
+
+        let mut mail = type_hash(&["Mail(Person from,Person to,string contents)", person_type]).to_vec();
+        mail.extend_from_slice(&person_hash("Cow", "0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826"));
+        mail.extend_from_slice(&person_hash("Bob", "0xbBbBBBBbbBBBbbbBbbBbbbbBBbBbbbbBbBbbBBbB"));
+        mail.extend_from_slice(&keccak256(b"Hello, Bob!"));
+        let mail_hash = keccak256(&mail);
+
+        let mut chain_id = [0u8; 32];
+        chain_id[31] = 1;
+        let mut domain = type_hash(&["EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)"]).to_vec();
+        domain.extend_from_slice(&keccak256(b"Ether Mail"));
+        domain.extend_from_slice(&keccak256(b"1"));
+        domain.extend_from_slice(&chain_id);
+        domain.extend_from_slice(&address_word("0xCcCCccccCCCCcCCCCCCcCcCccCcCCCcCcccccccC"));
+        let domain_separator = keccak256(&domain);
+
+        assert_eq!(
+            hex::encode(domain_separator),
+            "f2cee375fa42b42143804025fc449deafd50cc031ca257e0b194a650a912090f"
+        );
+        assert_eq!(hex::encode(mail_hash), "c52c0ee5d84264471806290a3f2c4cecfc5490626bf912d01f240d7a274b371e");
+        assert_eq!(
+            hex::encode(eip712_digest(domain_separator, mail_hash)),
+            "be609aee343fb3c4b28e1df9e632fca64fcfaede20f02e86244efddf30957bd2"
+        );
+    }
+
+    #[test]
+    fn decimals_are_printed_with_eighteen_digits() {
+        assert_eq!(dec_string(FPDecimal::must_from_str("10.5")), "10.500000000000000000");
+        assert_eq!(dec_string(FPDecimal::must_from_str("0.000001")), "0.000001000000000000");
+        assert_eq!(dec_string(FPDecimal::ZERO), "0.000000000000000000");
+    }
+
+    // the hashes themselves are checked against orders created on chain by the injective-cosmwasm-mock integration tests
+    #[test]
+    fn orders_without_fee_recipient_are_hashed_with_the_sender() {
+        let sender = Addr::unchecked("inj1cml96vmptgw99syqrrz8az79xer2pcgp0a885r");
+        let other_sender = Addr::unchecked("inj1qg5ega6dykkxc307y25pecuufrjkxkag6xhp6y");
+        let spot_order = |fee_recipient: Option<Addr>| {
+            SpotOrder::new(
+                FPDecimal::must_from_str("10.5"),
+                FPDecimal::TWO,
+                OrderType::Buy,
+                &MarketId::unchecked(MARKET_ID),
+                SubaccountId::unchecked(SUBACCOUNT_ID),
+                fee_recipient,
+                None,
+            )
+        };
+        let derivative_order = |fee_recipient: Option<Addr>| {
+            DerivativeOrder::new(
+                FPDecimal::must_from_str("10.5"),
+                FPDecimal::TWO,
+                FPDecimal::must_from_str("21"),
+                OrderType::SellPo,
+                MarketId::unchecked(MARKET_ID),
+                SubaccountId::unchecked(SUBACCOUNT_ID),
+                fee_recipient,
+                None,
+            )
+        };
+
+        assert_eq!(
+            compute_spot_order_hash(&spot_order(None), &sender, 1),
+            compute_spot_order_hash(&spot_order(Some(sender.clone())), &other_sender, 1)
+        );
+        assert_ne!(
+            compute_spot_order_hash(&spot_order(None), &sender, 1),
+            compute_spot_order_hash(&spot_order(None), &other_sender, 1)
+        );
+        assert_eq!(
+            compute_derivative_order_hash(&derivative_order(None), &sender, 1),
+            compute_derivative_order_hash(&derivative_order(Some(sender.clone())), &other_sender, 1)
+        );
+    }
+
+    #[test]
+    fn generator_increments_the_nonce_before_hashing() {
+        let sender = Addr::unchecked("inj1cml96vmptgw99syqrrz8az79xer2pcgp0a885r");
+        let order = SpotOrder::new(
+            FPDecimal::must_from_str("10.5"),
+            FPDecimal::TWO,
+            OrderType::Buy,
+            &MarketId::unchecked(MARKET_ID),
+            SubaccountId::unchecked(SUBACCOUNT_ID),
+            None,
+            None,
+        );
+
+        let mut generator = OrderHashGenerator::new(sender.clone(), 4);
+        assert_eq!(generator.next_spot_order_hash(&order), Ok(compute_spot_order_hash(&order, &sender, 5)));
+        assert_eq!(generator.next_spot_order_hash(&order), Ok(compute_spot_order_hash(&order, &sender, 6)));
+        assert_ne!(compute_spot_order_hash(&order, &sender, 5), compute_spot_order_hash(&order, &sender, 6));
+        assert_eq!(generator.nonce(), 6);
+    }
+
+    #[test]
+    fn generator_fails_once_the_nonce_is_exhausted() {
+        let sender = Addr::unchecked("inj1cml96vmptgw99syqrrz8az79xer2pcgp0a885r");
+        let order = DerivativeOrder::new(
+            FPDecimal::must_from_str("10.5"),
+            FPDecimal::TWO,
+            FPDecimal::must_from_str("21"),
+            OrderType::Sell,
+            MarketId::unchecked(MARKET_ID),
+            SubaccountId::unchecked(SUBACCOUNT_ID),
+            None,
+            None,
+        );
+
+        let mut generator = OrderHashGenerator::new(sender.clone(), u32::MAX - 1);
+        assert_eq!(
+            generator.next_derivative_order_hash(&order),
+            Ok(compute_derivative_order_hash(&order, &sender, u32::MAX))
+        );
+        assert_eq!(generator.next_derivative_order_hash(&order), Err(InjectiveError::TradeNonceExhausted));
+        assert_eq!(generator.nonce(), u32::MAX);
+    }
+}
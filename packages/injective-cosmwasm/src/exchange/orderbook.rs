@@ -0,0 +1,261 @@
+use injective_math::{utils::round_to_min_tick, FPDecimal};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::error::InjectiveError;
+use crate::exchange::{market::GenericMarket, order::OrderSide, response::QueryOrderbookResponse, types::PriceLevel};
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum FillTarget {
+    /// Fill up to this base quantity
+    Quantity(FPDecimal),
+    /// Fill until this much quote notional has been traded, fees excluded
+    Notional(FPDecimal),
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct OrderbookFill {
+    pub filled_quantity: FPDecimal,
+    pub notional: FPDecimal,
+    pub average_price: FPDecimal,
+    pub worst_price: FPDecimal,
+    /// Relative price impact of the fill against the mid price, positive when worse than mid
+    pub slippage: Option<FPDecimal>,
+    pub fee: FPDecimal,
+    /// False if the book did not have enough liquidity to reach the target
+    pub is_complete: bool,
+}
+
+impl OrderbookFill {
+    /// Quote amount a buyer pays, fees included
+    pub fn total_cost(&self) -> FPDecimal {
+        self.notional + self.fee
+    }
+
+    /// Quote amount a seller receives, fees deducted
+    pub fn net_proceeds(&self) -> FPDecimal {
+        self.notional - self.fee
+    }
+}
+
+/// Simulates taker fills against orderbook levels, which are expected best price first as returned by the orderbook queries
+pub struct OrderbookSimulation<'a> {
+    buys: &'a [PriceLevel],
+    sells: &'a [PriceLevel],
+}
+
+impl<'a> OrderbookSimulation<'a> {
+    pub fn new(buys: &'a [PriceLevel], sells: &'a [PriceLevel]) -> Self {
+        OrderbookSimulation { buys, sells }
+    }
+
+    pub fn from_response(response: &'a QueryOrderbookResponse) -> Self {
+        OrderbookSimulation::new(&response.buys_price_level, &response.sells_price_level)
+    }
+
+    pub fn mid_price(&self) -> Option<FPDecimal> {
+        match (self.buys.first(), self.sells.first()) {
+            (Some(best_buy), Some(best_sell)) => Some((best_buy.p + best_sell.p) / FPDecimal::TWO),
+            _ => None,
+        }
+    }
+
+    /// Fill without fees nor rounding to the market's quantity tick.
+    /// Fails with `InvalidPrice` if a walked level is not priced above zero.
+    pub fn simulate(&self, side: OrderSide, target: FillTarget) -> Result<OrderbookFill, InjectiveError> {
+        self.fill(side, target, None, FPDecimal::ZERO)
+    }
+
+    /// Fill with quantities rounded down to the market's min quantity tick and the taker fee applied.
+    /// Pass the market's atomic execution fee multiplier for atomic orders.
+    pub fn simulate_for_market(
+        &self,
+        market: &impl GenericMarket,
+        side: OrderSide,
+        target: FillTarget,
+        atomic_fee_multiplier: Option<FPDecimal>,
+    ) -> Result<OrderbookFill, InjectiveError> {
+        let fee_rate = market.get_taker_fee_rate() * atomic_fee_multiplier.unwrap_or(FPDecimal::ONE);
+        self.fill(side, target, Some(market.min_quantity_tick_size()), fee_rate)
+    }
+
+    fn fill(
+        &self,
+        side: OrderSide,
+        target: FillTarget,
+        min_quantity_tick_size: Option<FPDecimal>,
+        fee_rate: FPDecimal,
+    ) -> Result<OrderbookFill, InjectiveError> {
+        let levels = match side {
+            OrderSide::Buy => self.sells,
+            OrderSide::Sell => self.buys,
+            OrderSide::Unspecified => return Err(InjectiveError::InvalidOrderSide),
+        };
+
+        let round_quantity = |quantity: FPDecimal| match min_quantity_tick_size {
+            Some(min_tick) => round_to_min_tick(quantity, min_tick),
+            None => quantity,
+        };
+
+        let target = match target {
+            FillTarget::Quantity(quantity) => FillTarget::Quantity(round_quantity(quantity)),
+            notional => notional,
+        };
+
+        let mut filled_quantity = FPDecimal::ZERO;
+        let mut notional = FPDecimal::ZERO;
+        let mut worst_price = FPDecimal::ZERO;
+        let mut is_complete = false;
+
+        for level in levels {
+            if level.p <= FPDecimal::ZERO {
+                return Err(InjectiveError::InvalidPrice { price: level.p });
+            }
+
+            let remaining = match target {
+                FillTarget::Quantity(quantity) => quantity - filled_quantity,
+                FillTarget::Notional(target_notional) => round_quantity((target_notional - notional) / level.p),
+            };
+            if remaining <= FPDecimal::ZERO {
+                is_complete = true;
+                break;
+            }
+
+            let quantity = remaining.min(level.q);
+            filled_quantity += quantity;
+            notional += quantity * level.p;
+            worst_price = level.p;
+
+            if quantity < level.q {
+                is_complete = true;
+                break;
+            }
+        }
+
+        if !is_complete {
+            is_complete = match target {
+                FillTarget::Quantity(quantity) => filled_quantity >= quantity,
+                FillTarget::Notional(target_notional) => notional >= target_notional,
+            };
+        }
+
+        let average_price = if filled_quantity.is_zero() {
+            FPDecimal::ZERO
+        } else {
+            notional / filled_quantity
+        };
+
+        let slippage = self.mid_price().filter(|_| !filled_quantity.is_zero()).map(|mid| match side {
+            OrderSide::Sell => (mid - average_price) / mid,
+            _ => (average_price - mid) / mid,
+        });
+
+        Ok(OrderbookFill {
+            filled_quantity,
+            notional,
+            average_price,
+            worst_price,
+            slippage,
+            fee: notional * fee_rate,
+            is_complete,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use injective_math::FPDecimal;
+
+    use crate::exchange::{
+        market::MarketStatus,
+        order::OrderSide,
+        orderbook::{FillTarget, OrderbookSimulation},
+        types::PriceLevel,
+    };
+    use crate::{InjectiveError, MarketId, SpotMarket};
+
+    fn level(p: &str, q: &str) -> PriceLevel {
+        PriceLevel::new(FPDecimal::must_from_str(p), FPDecimal::must_from_str(q))
+    }
+
+    #[test]
+    fn buy_walks_sell_levels_by_quantity() {
+        let buys = vec![level("9", "10")];
+        let sells = vec![level("11", "1"), level("12", "2"), level("13", "5")];
+        let simulation = OrderbookSimulation::new(&buys, &sells);
+
+        let fill = simulation
+            .simulate(OrderSide::Buy, FillTarget::Quantity(FPDecimal::must_from_str("2")))
+            .unwrap();
+
+        assert_eq!(fill.filled_quantity, FPDecimal::must_from_str("2"));
+        assert_eq!(fill.notional, FPDecimal::must_from_str("23"));
+        assert_eq!(fill.average_price, FPDecimal::must_from_str("11.5"));
+        assert_eq!(fill.worst_price, FPDecimal::must_from_str("12"));
+        assert_eq!(fill.slippage, Some(FPDecimal::must_from_str("0.15")));
+        assert!(fill.is_complete);
+    }
+
+    #[test]
+    fn sell_reports_incomplete_fill_when_book_is_too_thin() {
+        let buys = vec![level("10", "1"), level("9", "1")];
+        let simulation = OrderbookSimulation::new(&buys, &[]);
+
+        let fill = simulation
+            .simulate(OrderSide::Sell, FillTarget::Quantity(FPDecimal::must_from_str("5")))
+            .unwrap();
+
+        assert_eq!(fill.filled_quantity, FPDecimal::TWO);
+        assert_eq!(fill.notional, FPDecimal::must_from_str("19"));
+        assert_eq!(fill.slippage, None);
+        assert!(!fill.is_complete);
+    }
+
+    #[test]
+    fn zero_price_levels_are_rejected() {
+        let sells = vec![level("0", "5"), level("10", "1")];
+        let simulation = OrderbookSimulation::new(&[], &sells);
+
+        for target in [FillTarget::Notional(FPDecimal::TEN), FillTarget::Quantity(FPDecimal::ONE)] {
+            assert_eq!(
+                simulation.simulate(OrderSide::Buy, target),
+                Err(InjectiveError::InvalidPrice { price: FPDecimal::ZERO })
+            );
+        }
+    }
+
+    #[test]
+    fn notional_target_rounds_to_market_tick_and_applies_fee() {
+        let market = SpotMarket {
+            ticker: "INJ/USDT".to_string(),
+            base_denom: "inj".to_string(),
+            quote_denom: "usdt".to_string(),
+            maker_fee_rate: FPDecimal::must_from_str("-0.0001"),
+            taker_fee_rate: FPDecimal::must_from_str("0.001"),
+            relayer_fee_share_rate: FPDecimal::must_from_str("0.4"),
+            market_id: MarketId::unchecked("0x0611780ba69656949525013d947713300f56c37b6175e02f26bffa495c3208fe"),
+            status: MarketStatus::Active,
+            min_price_tick_size: FPDecimal::must_from_str("0.01"),
+            min_quantity_tick_size: FPDecimal::must_from_str("0.1"),
+        };
+        let sells = vec![level("10", "1"), level("20", "10")];
+        let simulation = OrderbookSimulation::new(&[], &sells);
+
+        let fill = simulation
+            .simulate_for_market(
+                &market,
+                OrderSide::Buy,
+                FillTarget::Notional(FPDecimal::must_from_str("25")),
+                Some(FPDecimal::must_from_str("2.5")),
+            )
+            .unwrap();
+
+        // 10 from the first level, then 15 / 20 = 0.75 rounded down to 0.7
+        assert_eq!(fill.filled_quantity, FPDecimal::must_from_str("1.7"));
+        assert_eq!(fill.notional, FPDecimal::must_from_str("24"));
+        assert_eq!(fill.fee, FPDecimal::must_from_str("0.06"));
+        assert_eq!(fill.total_cost(), FPDecimal::must_from_str("24.06"));
+        assert!(fill.is_complete);
+    }
+}
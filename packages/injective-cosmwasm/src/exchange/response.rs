@@ -1,11 +1,14 @@
+use std::collections::BTreeMap;
+
 use cosmwasm_std::Uint128;
 use injective_math::FPDecimal;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::exchange::{
-    derivative::{EffectivePosition, Position, TrimmedDerivativeLimitOrder},
-    derivative_market::{FullDerivativeMarket, PerpetualMarketFunding, PerpetualMarketInfo},
+    binary_options_market::BinaryOptionsMarket,
+    derivative::{DerivativePosition, EffectivePosition, Position, TrimmedDerivativeLimitOrder},
+    derivative_market::{ExpiryFuturesMarketInfo, FullDerivativeMarket, PerpetualMarketFunding, PerpetualMarketInfo},
     spot::TrimmedSpotLimitOrder,
     spot_market::SpotMarket,
     types::{DenomDecimals, Deposit, MarketVolume, Params, PriceLevel, VolumeByType},
@@ -22,6 +25,25 @@ pub struct SubaccountDepositResponse {
     pub deposits: Deposit,
 }
 
+/// Deposits of a subaccount keyed by denom
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct SubaccountDepositsResponse {
+    #[serde(default)]
+    pub deposits: BTreeMap<String, Deposit>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct SubaccountTradeNonceResponse {
+    #[serde(default)]
+    pub nonce: u32,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct SubaccountPositionsResponse {
+    #[serde(default)]
+    pub state: Vec<DerivativePosition>,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 pub struct SubaccountEffectivePositionInMarketResponse {
     pub state: Option<EffectivePosition>,
@@ -37,6 +59,11 @@ pub struct PerpetualMarketInfoResponse {
     pub info: Option<PerpetualMarketInfo>,
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct ExpiryFuturesMarketInfoResponse {
+    pub info: Option<ExpiryFuturesMarketInfo>,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 pub struct PerpetualMarketFundingResponse {
     pub state: Option<PerpetualMarketFunding>,
@@ -62,6 +89,11 @@ pub struct SpotMarketResponse {
     pub market: Option<SpotMarket>,
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct BinaryOptionsMarketResponse {
+    pub market: Option<BinaryOptionsMarket>,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 pub struct MarketMidPriceAndTOBResponse {
     pub mid_price: Option<FPDecimal>,
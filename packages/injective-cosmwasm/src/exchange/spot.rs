@@ -279,5 +279,8 @@ pub struct SpotMarketOrderResults {
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 pub struct MsgCreateSpotMarketOrderResponse {
     pub order_hash: String,
-    pub results: SpotMarketOrderResults,
+    #[serde(default)]
+    pub cid: Option<String>,
+    #[serde(default)]
+    pub results: Option<SpotMarketOrderResults>,
 }
@@ -1,6 +1,7 @@
-use crate::exchange::types::MarketId;
+use crate::error::InjectiveError;
+use crate::exchange::types::{DenomDecimals, MarketId};
 use cosmwasm_std::StdResult;
-use injective_math::FPDecimal;
+use injective_math::{scale::Scaled, FPDecimal};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use tiny_keccak::Keccak;
@@ -57,13 +58,64 @@ impl GenericMarket for SpotMarket {
     }
 }
 
+impl SpotMarket {
+    /// Builds the market's scale from a `query_denom_decimals` response covering both its base and quote denoms
+    pub fn human_scale(&self, denom_decimals: &[DenomDecimals]) -> Result<SpotMarketScale, InjectiveError> {
+        let decimals_of = |denom: &str| {
+            denom_decimals
+                .iter()
+                .find(|d| d.denom == denom)
+                .map(|d| d.decimals)
+                .ok_or_else(|| InjectiveError::MissingDenomDecimals { denom: denom.to_string() })
+        };
+
+        Ok(SpotMarketScale::new(decimals_of(&self.base_denom)?, decimals_of(&self.quote_denom)?))
+    }
+}
+
+/// Converts spot prices and quantities between chain units and human units, e.g. 1 INJ instead of 10^18 inj
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, JsonSchema)]
+pub struct SpotMarketScale {
+    pub base_decimals: u64,
+    pub quote_decimals: u64,
+}
+
+impl SpotMarketScale {
+    pub fn new(base_decimals: u64, quote_decimals: u64) -> Self {
+        SpotMarketScale {
+            base_decimals,
+            quote_decimals,
+        }
+    }
+
+    pub fn price_to_human(&self, price: FPDecimal) -> FPDecimal {
+        price.scaled(self.price_exponent())
+    }
+
+    pub fn price_from_human(&self, price: FPDecimal) -> FPDecimal {
+        price.scaled(-self.price_exponent())
+    }
+
+    pub fn quantity_to_human(&self, quantity: FPDecimal) -> FPDecimal {
+        quantity.scaled(-(self.base_decimals as i32))
+    }
+
+    pub fn quantity_from_human(&self, quantity: FPDecimal) -> FPDecimal {
+        quantity.scaled(self.base_decimals as i32)
+    }
+
+    fn price_exponent(&self) -> i32 {
+        self.base_decimals as i32 - self.quote_decimals as i32
+    }
+}
+
 pub fn calculate_spot_market_id(base_denom: String, quote_denom: String) -> StdResult<MarketId> {
     let mut hasher = Keccak::new_keccak256();
     hasher.update((base_denom + &quote_denom).as_bytes());
     let mut res = [0u8; 32];
     hasher.finalize(&mut res);
 
-    MarketId::new(format!("0x{}", hex::encode(res)))
+    Ok(MarketId::new(format!("0x{}", hex::encode(res)))?)
 }
 
 #[cfg(test)]
@@ -83,4 +135,44 @@ mod tests {
             "calculate_spot_market_id did not produce the expected hash"
         );
     }
+
+    #[test]
+    fn human_scale_converts_prices_and_quantities() {
+        let market = SpotMarket {
+            ticker: "INJ/USDT".to_string(),
+            base_denom: "inj".to_string(),
+            quote_denom: "usdt".to_string(),
+            maker_fee_rate: FPDecimal::ZERO,
+            taker_fee_rate: FPDecimal::ZERO,
+            relayer_fee_share_rate: FPDecimal::ZERO,
+            market_id: MarketId::unchecked("0x0611780ba69656949525013d947713300f56c37b6175e02f26bffa495c3208fe"),
+            status: MarketStatus::Active,
+            min_price_tick_size: FPDecimal::must_from_str("0.000000000000001"),
+            min_quantity_tick_size: FPDecimal::must_from_str("1000000000000000"),
+        };
+        let decimals = vec![
+            DenomDecimals {
+                denom: "usdt".to_string(),
+                decimals: 6,
+            },
+            DenomDecimals {
+                denom: "inj".to_string(),
+                decimals: 18,
+            },
+        ];
+
+        let scale = market.human_scale(&decimals).unwrap();
+
+        assert_eq!(scale.price_to_human(market.min_price_tick_size), FPDecimal::must_from_str("0.001"));
+        assert_eq!(scale.quantity_to_human(market.min_quantity_tick_size), FPDecimal::must_from_str("0.001"));
+        assert_eq!(
+            scale.price_from_human(FPDecimal::must_from_str("25.5")),
+            FPDecimal::must_from_str("0.0000000000255")
+        );
+        assert_eq!(scale.quantity_from_human(FPDecimal::TWO), FPDecimal::must_from_str("2000000000000000000"));
+        assert_eq!(
+            market.human_scale(&decimals[..1]),
+            Err(InjectiveError::MissingDenomDecimals { denom: "inj".to_string() })
+        );
+    }
 }
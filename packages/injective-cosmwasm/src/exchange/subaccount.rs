@@ -5,18 +5,27 @@ use subtle_encoding::bech32;
 
 use ethereum_types::H160;
 
-use crate::{exchange::types::SubaccountId, InjectiveQueryWrapper};
+use crate::{error::InjectiveError, exchange::types::SubaccountId, InjectiveQueryWrapper};
 
 pub fn get_default_subaccount_id_for_checked_address(addr: &Addr) -> SubaccountId {
     checked_address_to_subaccount_id(addr, 0)
 }
 
+pub fn try_get_default_subaccount_id_for_checked_address(addr: &Addr) -> Result<SubaccountId, InjectiveError> {
+    try_checked_address_to_subaccount_id(addr, 0)
+}
+
+/// Panics if `addr` is not a valid bech32 address, see [`try_checked_address_to_subaccount_id`]
 pub fn checked_address_to_subaccount_id(addr: &Addr, nonce: u32) -> SubaccountId {
-    let address_str = bech32_to_hex(addr);
+    try_checked_address_to_subaccount_id(addr, nonce).expect("failed to create subaccount_id")
+}
+
+pub fn try_checked_address_to_subaccount_id(addr: &Addr, nonce: u32) -> Result<SubaccountId, InjectiveError> {
+    let address_str = try_bech32_to_hex(addr)?;
     let hex_nonce = format!("{nonce:08x}");
     let nonce_str = left_pad_with_zeroes(hex_nonce, 24);
 
-    SubaccountId::new(format!("{address_str}{nonce_str}")).expect("failed to create subaccount_id")
+    SubaccountId::new(format!("{address_str}{nonce_str}")).map_err(|e| InjectiveError::InvalidSubaccountId(e.to_string()))
 }
 
 pub fn is_default_subaccount(subaccount_id: &SubaccountId) -> bool {
@@ -33,16 +42,40 @@ fn left_pad_with_zeroes(mut input: String, length: usize) -> String {
     input
 }
 
+/// Panics if `addr` is not a valid bech32 address, see [`try_bech32_to_hex`]
 pub fn bech32_to_hex(addr: &Addr) -> String {
-    let decoded_bytes = bech32::decode(addr.as_str()).unwrap().1;
+    try_bech32_to_hex(addr).unwrap()
+}
+
+pub fn try_bech32_to_hex(addr: &Addr) -> Result<String, InjectiveError> {
+    let invalid_bech32 = |reason: String| InjectiveError::InvalidBech32 {
+        address: addr.to_string(),
+        reason,
+    };
+
+    let decoded_bytes = bech32::decode(addr.as_str()).map_err(|e| invalid_bech32(e.to_string()))?.1;
+    if decoded_bytes.len() != 20 {
+        return Err(invalid_bech32(format!("expected 20 bytes, got {}", decoded_bytes.len())));
+    }
+
     let decoded_h160 = H160::from_slice(&decoded_bytes);
-    let decoded_string = format!("{decoded_h160:?}");
-    decoded_string
+    Ok(format!("{decoded_h160:?}"))
 }
 
+/// Panics if `addr` is not a 0x prefixed ethereum address, see [`try_addr_to_bech32`]
 pub fn addr_to_bech32(addr: String) -> String {
-    let encoded_bytes = H160::from_str(&addr[2..addr.len()]).unwrap();
-    bech32::encode("inj", encoded_bytes)
+    try_addr_to_bech32(&addr).unwrap()
+}
+
+pub fn try_addr_to_bech32(addr: &str) -> Result<String, InjectiveError> {
+    let invalid_address = |reason: String| InjectiveError::InvalidEthereumAddress {
+        address: addr.to_string(),
+        reason,
+    };
+
+    let hex_address = addr.strip_prefix("0x").ok_or_else(|| invalid_address("missing 0x prefix".to_string()))?;
+    let encoded_bytes = H160::from_str(hex_address).map_err(|e| invalid_address(e.to_string()))?;
+    Ok(bech32::encode("inj", encoded_bytes))
 }
 
 pub fn subaccount_id_to_ethereum_address(subaccount_id: &SubaccountId) -> String {
@@ -51,8 +84,16 @@ pub fn subaccount_id_to_ethereum_address(subaccount_id: &SubaccountId) -> String
 }
 
 pub fn subaccount_id_to_injective_address(subaccount_id: &SubaccountId, deps: &Deps<InjectiveQueryWrapper>) -> StdResult<Addr> {
+    Ok(try_subaccount_id_to_injective_address(subaccount_id, deps)?)
+}
+
+pub fn try_subaccount_id_to_injective_address(subaccount_id: &SubaccountId, deps: &Deps<InjectiveQueryWrapper>) -> Result<Addr, InjectiveError> {
     let ethereum_address = subaccount_id_to_ethereum_address(subaccount_id);
-    deps.api.addr_validate(addr_to_bech32(ethereum_address).as_str())
+    let address = try_addr_to_bech32(&ethereum_address)?;
+    deps.api.addr_validate(&address).map_err(|e| InjectiveError::InvalidBech32 {
+        address,
+        reason: e.to_string(),
+    })
 }
 
 pub fn subaccount_id_to_unchecked_injective_address(subaccount_id: &SubaccountId) -> String {
@@ -63,9 +104,10 @@ pub fn subaccount_id_to_unchecked_injective_address(subaccount_id: &SubaccountId
 #[cfg(test)]
 mod tests {
     use crate::{
+        error::InjectiveError,
         exchange::subaccount::{
             bech32_to_hex, checked_address_to_subaccount_id, get_default_subaccount_id_for_checked_address, subaccount_id_to_injective_address,
-            SubaccountId,
+            try_addr_to_bech32, try_bech32_to_hex, try_checked_address_to_subaccount_id, SubaccountId,
         },
         mock_dependencies,
     };
@@ -91,6 +133,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn fallible_address_helpers_test() {
+        assert_eq!(
+            try_bech32_to_hex(&Addr::unchecked("inj1khsfhyavadcvzug67pufytaz2cq36ljkrsr0nv")).unwrap(),
+            "0xb5e09b93aceb70c1711af078922fa256011d7e56"
+        );
+        assert!(matches!(
+            try_bech32_to_hex(&Addr::unchecked("not-an-address")),
+            Err(InjectiveError::InvalidBech32 { .. })
+        ));
+        assert!(matches!(
+            try_checked_address_to_subaccount_id(&Addr::unchecked("inj1khsfhyavadcvzug67pufytaz2cq36ljkrsr0nz"), 1),
+            Err(InjectiveError::InvalidBech32 { .. })
+        ));
+
+        assert_eq!(
+            try_addr_to_bech32("0xb5e09b93aceb70c1711af078922fa256011d7e56").unwrap(),
+            "inj1khsfhyavadcvzug67pufytaz2cq36ljkrsr0nv"
+        );
+        assert!(matches!(
+            try_addr_to_bech32("b5e09b93aceb70c1711af078922fa256011d7e56"),
+            Err(InjectiveError::InvalidEthereumAddress { .. })
+        ));
+        assert!(matches!(try_addr_to_bech32("0xzz"), Err(InjectiveError::InvalidEthereumAddress { .. })));
+    }
+
     #[test]
     fn subaccount_id_to_address_test() {
         let subaccount_id = "0xb5e09b93aceb70c1711af078922fa256011d7e56000000000000000000000000";
@@ -1,4 +1,4 @@
-use cosmwasm_std::{Coin, Empty, StdError, StdResult};
+use cosmwasm_std::{Coin, StdError, StdResult};
 use cw_storage_plus::{Key, KeyDeserialize, Prefixer, PrimaryKey};
 use injective_math::FPDecimal;
 use schemars::JsonSchema;
@@ -7,6 +7,7 @@ use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
 use serde_repr::{Deserialize_repr, Serialize_repr};
 use std::fmt;
 
+use crate::error::InjectiveError;
 use crate::InjectiveQuerier;
 
 /// Params is the response type for the exchange params
@@ -83,6 +84,7 @@ pub struct MarketVolume {
 pub enum MarketType {
     Spot,
     Derivative,
+    BinaryOptions,
 }
 
 #[derive(Serialize_repr, Deserialize_repr, Default, Clone, Debug, PartialEq, Eq, JsonSchema, Copy)]
@@ -99,18 +101,22 @@ pub enum AtomicMarketOrderAccessLevel {
 pub struct MarketId(String);
 
 impl MarketId {
-    pub fn new<S>(market_id_s: S) -> StdResult<Self>
+    pub fn new<S>(market_id_s: S) -> Result<Self, InjectiveError>
     where
         S: Into<String>,
     {
         let market_id = market_id_s.into();
 
         if !market_id.starts_with("0x") {
-            return Err(StdError::generic_err("Invalid prefix: market_id must start with 0x"));
+            return Err(InjectiveError::InvalidMarketId(
+                "Invalid prefix: market_id must start with 0x".to_string(),
+            ));
         }
 
         if market_id.len() != 66 {
-            return Err(StdError::generic_err("Invalid length: market_id must be exactly 66 characters"));
+            return Err(InjectiveError::InvalidMarketId(
+                "Invalid length: market_id must be exactly 66 characters".to_string(),
+            ));
         }
 
         Ok(Self(market_id.to_lowercase()))
@@ -120,17 +126,17 @@ impl MarketId {
         self.0.as_str()
     }
 
-    pub fn validate(self, querier: &InjectiveQuerier, market_type: MarketType) -> StdResult<Self> {
-        match market_type {
-            MarketType::Spot => {
-                let _spot_market = querier.query_spot_market(&self)?;
-                Empty {}
-            }
-            MarketType::Derivative => {
-                let _derivative_market = querier.query_derivative_market(&self)?;
-                Empty {}
-            }
-        };
+    pub fn validate(self, querier: &InjectiveQuerier, market_type: MarketType) -> Result<Self, InjectiveError> {
+        let market_exists = match market_type {
+            MarketType::Spot => querier.query_spot_market(&self).map(|res| res.market.is_some()),
+            MarketType::Derivative => querier.query_derivative_market(&self).map(|res| res.market.is_some()),
+            MarketType::BinaryOptions => querier.query_binary_options_market(&self).map(|res| res.market.is_some()),
+        }
+        .map_err(|e| InjectiveError::QueryFailed(e.to_string()))?;
+
+        if !market_exists {
+            return Err(InjectiveError::MarketNotFound { market_id: self });
+        }
 
         Ok(self)
     }
@@ -184,18 +190,22 @@ impl<'de> Deserialize<'de> for MarketId {
 pub struct SubaccountId(String);
 
 impl SubaccountId {
-    pub fn new<S>(subaccount_id_s: S) -> Result<SubaccountId, StdError>
+    pub fn new<S>(subaccount_id_s: S) -> Result<SubaccountId, InjectiveError>
     where
         S: Into<String>,
     {
         let subaccount_id = subaccount_id_s.into();
 
         if !subaccount_id.starts_with("0x") {
-            return Err(StdError::generic_err("Invalid prefix: subaccount_id must start with 0x"));
+            return Err(InjectiveError::InvalidSubaccountId(
+                "Invalid prefix: subaccount_id must start with 0x".to_string(),
+            ));
         }
 
         if subaccount_id.len() != 66 {
-            return Err(StdError::generic_err("Invalid length: subaccount_id must be exactly 66 characters"));
+            return Err(InjectiveError::InvalidSubaccountId(
+                "Invalid length: subaccount_id must be exactly 66 characters".to_string(),
+            ));
         }
 
         Ok(Self(subaccount_id.to_lowercase()))
@@ -251,7 +261,7 @@ pub struct ShortSubaccountId(String);
 const MAX_SHORT_SUBACCOUNT_NONCE: u16 = 999;
 
 impl ShortSubaccountId {
-    pub fn new<S>(id_s: S) -> Result<ShortSubaccountId, StdError>
+    pub fn new<S>(id_s: S) -> Result<ShortSubaccountId, InjectiveError>
     where
         S: Into<String>,
     {
@@ -280,10 +290,10 @@ impl ShortSubaccountId {
         Self(id_s.into())
     }
 
-    pub fn validate(&self) -> StdResult<Self> {
+    pub fn validate(&self) -> Result<Self, InjectiveError> {
         let as_decimal = match u32::from_str_radix(self.as_str(), 16) {
             Ok(dec) => Ok(dec),
-            Err(_) => Err(StdError::generic_err(format!(
+            Err(_) => Err(InjectiveError::InvalidSubaccountId(format!(
                 "Invalid value: ShortSubaccountId was not a hexadecimal number: {}",
                 &self.0
             ))),
@@ -291,7 +301,7 @@ impl ShortSubaccountId {
 
         match as_decimal?.to_string().parse::<u16>() {
             Ok(value) if value <= MAX_SHORT_SUBACCOUNT_NONCE => Ok(self.clone()),
-            _ => Err(StdError::generic_err(format!(
+            _ => Err(InjectiveError::InvalidSubaccountId(format!(
                 "Invalid value: ShortSubaccountId must be a number between 0-999, but {} was received",
                 &self.0
             ))),
@@ -439,7 +449,7 @@ mod tests {
     use serde_test::{assert_de_tokens, assert_ser_tokens, Token};
     use std::panic::catch_unwind;
 
-    use crate::{MarketId, ShortSubaccountId, SubaccountId};
+    use crate::{InjectiveError, MarketId, ShortSubaccountId, SubaccountId};
 
     #[test]
     fn unchecked_subaccount_id_to_lowercase() {
@@ -486,37 +496,44 @@ mod tests {
         let wrong_prefix_err = SubaccountId::new("00B5e09b93aCEb70C1711aF078922fA256011D7e56000000000000000000000045").unwrap_err();
         assert_eq!(
             wrong_prefix_err,
-            StdError::generic_err("Invalid prefix: subaccount_id must start with 0x")
+            InjectiveError::InvalidSubaccountId("Invalid prefix: subaccount_id must start with 0x".to_string())
         );
 
         let wrong_length_err = SubaccountId::new("0xB5e09b93aCEb70C1711aF078922fA256011D7e5600000000000000000000004").unwrap_err();
         assert_eq!(
             wrong_length_err,
-            StdError::generic_err("Invalid length: subaccount_id must be exactly 66 characters")
+            InjectiveError::InvalidSubaccountId("Invalid length: subaccount_id must be exactly 66 characters".to_string())
         );
 
         let wrong_length_err = SubaccountId::new("0xB5e09b93aCEb70C1711aF078922fA256011D7e560000000000000000000000451").unwrap_err();
         assert_eq!(
             wrong_length_err,
-            StdError::generic_err("Invalid length: subaccount_id must be exactly 66 characters")
+            InjectiveError::InvalidSubaccountId("Invalid length: subaccount_id must be exactly 66 characters".to_string())
         );
     }
 
     #[test]
     fn market_id_checks() {
         let wrong_prefix_err = MarketId::new("0001EDFAB47F124748DC89998EB33144AF734484BA07099014594321729A0CA16B").unwrap_err();
-        assert_eq!(wrong_prefix_err, StdError::generic_err("Invalid prefix: market_id must start with 0x"));
+        assert_eq!(
+            wrong_prefix_err,
+            InjectiveError::InvalidMarketId("Invalid prefix: market_id must start with 0x".to_string())
+        );
+        assert_eq!(
+            StdError::from(wrong_prefix_err),
+            StdError::generic_err("Invalid prefix: market_id must start with 0x")
+        );
 
         let wrong_length_err = MarketId::new("0x01EDFAB47F124748DC89998EB33144AF734484BA07099014594321729A0CA16").unwrap_err();
         assert_eq!(
             wrong_length_err,
-            StdError::generic_err("Invalid length: market_id must be exactly 66 characters")
+            InjectiveError::InvalidMarketId("Invalid length: market_id must be exactly 66 characters".to_string())
         );
 
         let wrong_length_err = MarketId::new("0x01EDFAB47F124748DC89998EB33144AF734484BA07099014594321729A0CA16B2").unwrap_err();
         assert_eq!(
             wrong_length_err,
-            StdError::generic_err("Invalid length: market_id must be exactly 66 characters")
+            InjectiveError::InvalidMarketId("Invalid length: market_id must be exactly 66 characters".to_string())
         );
     }
 
@@ -0,0 +1,374 @@
+use std::collections::BTreeMap;
+use std::fmt::Debug;
+
+use cosmwasm_std::{to_json_binary, Addr, Binary, ContractResult, QuerierResult, SystemError, SystemResult};
+use injective_math::FPDecimal;
+use serde::Serialize;
+
+use crate::authz::types::PageRequest;
+use crate::exchange_mock_querier::{
+    HandlesAccountVolumeQuery, HandlesAddressGrantsQuery, HandlesBankAllBalancesQuery, HandlesBankBalanceQuery, HandlesByAddressQuery,
+    HandlesCodeInfo, HandlesContractInfo, HandlesDenomAuthorityMetadataQuery, HandlesDenomDecimalQuery, HandlesDenomSupplyQuery,
+    HandlesDerivativePriceLevelsQuery, HandlesGrantsQuery, HandlesMarketAndSubaccountQuery, HandlesMarketIdQuery, HandlesMarketVolatilityQuery,
+    HandlesMarketVolumeQuery, HandlesOraclePriceQuery, HandlesOracleVolatilityQuery, HandlesPriceLevelsQuery, HandlesPythPriceQuery, HandlesRawQuery,
+    HandlesSmartQuery, HandlesStakedAmountQuery, HandlesSubaccountAndDenomQuery, HandlesSubaccountIdQuery,
+    HandlesTraderDerivativeOrdersToCancelUpToAmountQuery, HandlesTraderSpotOrdersToCancelUpToAmountQuery,
+};
+use crate::oracle::{
+    types::{OracleHistoryOptions, OracleInfo, OracleType},
+    volatility::TradeHistoryOptions,
+};
+use crate::{CancellationStrategy, MarketId, OrderSide, SubaccountId};
+
+/// Handler answering with the response registered for the query's key, e.g. a market id, a (market id, subaccount id) pair or a denom.
+/// Queries without a registered response get the fallback set with `otherwise`, or a `SystemError` naming the missing key.
+///
+/// Keys per query: market id for market queries, (market id, subaccount id) for orders and positions, (subaccount id, denom) for
+/// deposits, (address, denom) for bank balances, (creator, subdenom) for authority metadata, (base, quote) for oracle prices,
+/// (base symbol, quote symbol, oracle type) for oracle volatility, (granter, grantee, msg type url) for grants, and the address,
+/// denom, price id or code id for the remaining queries. `HandlesDenomDecimalsQuery` is intentionally not implemented, its
+/// response lists several denoms at once so there is no single key to pick it by.
+///
+/// ```ignore
+/// deps.querier.spot_market_response_handler = Some(Box::new(
+///     KeyedHandler::new()
+///         .with(inj_market.market_id.clone(), &SpotMarketResponse { market: Some(inj_market) })
+///         .otherwise(&SpotMarketResponse { market: None }),
+/// ));
+/// ```
+pub struct KeyedHandler<K> {
+    responses: BTreeMap<K, QuerierResult>,
+    fallback: Option<QuerierResult>,
+}
+
+impl<K: Ord + Debug> KeyedHandler<K> {
+    pub fn new() -> Self {
+        KeyedHandler {
+            responses: BTreeMap::new(),
+            fallback: None,
+        }
+    }
+
+    pub fn with<R: Serialize>(self, key: K, response: &R) -> Self {
+        self.with_result(key, SystemResult::Ok(ContractResult::from(to_json_binary(response))))
+    }
+
+    /// Registers a raw result, used to make a single key fail
+    pub fn with_result(mut self, key: K, result: QuerierResult) -> Self {
+        self.responses.insert(key, result);
+        self
+    }
+
+    pub fn otherwise<R: Serialize>(self, response: &R) -> Self {
+        self.otherwise_result(SystemResult::Ok(ContractResult::from(to_json_binary(response))))
+    }
+
+    pub fn otherwise_result(mut self, result: QuerierResult) -> Self {
+        self.fallback = Some(result);
+        self
+    }
+
+    pub fn respond(&self, key: &K) -> QuerierResult {
+        match self.responses.get(key).or(self.fallback.as_ref()) {
+            Some(result) => result.clone(),
+            None => SystemResult::Err(SystemError::UnsupportedRequest {
+                kind: format!("no mock response registered for {:?}", key),
+            }),
+        }
+    }
+}
+
+impl<K: Ord + Debug> Default for KeyedHandler<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HandlesMarketIdQuery for KeyedHandler<MarketId> {
+    fn handle(&self, market_id: MarketId) -> QuerierResult {
+        self.respond(&market_id)
+    }
+}
+
+impl HandlesDerivativePriceLevelsQuery for KeyedHandler<MarketId> {
+    fn handle(&self, market_id: MarketId) -> QuerierResult {
+        self.respond(&market_id)
+    }
+}
+
+/// The registered orderbook is returned as is, whatever the requested side
+impl HandlesPriceLevelsQuery for KeyedHandler<MarketId> {
+    fn handle(&self, market_id: MarketId, _: OrderSide) -> QuerierResult {
+        self.respond(&market_id)
+    }
+}
+
+impl HandlesMarketVolumeQuery for KeyedHandler<MarketId> {
+    fn handle(&self, market_id: MarketId) -> QuerierResult {
+        self.respond(&market_id)
+    }
+}
+
+impl HandlesMarketVolatilityQuery for KeyedHandler<MarketId> {
+    fn handle(&self, market_id: MarketId, _: TradeHistoryOptions) -> QuerierResult {
+        self.respond(&market_id)
+    }
+}
+
+impl HandlesSubaccountIdQuery for KeyedHandler<SubaccountId> {
+    fn handle(&self, subaccount_id: SubaccountId) -> QuerierResult {
+        self.respond(&subaccount_id)
+    }
+}
+
+impl HandlesMarketAndSubaccountQuery for KeyedHandler<(MarketId, SubaccountId)> {
+    fn handle(&self, market_id: MarketId, subaccount_id: SubaccountId) -> QuerierResult {
+        self.respond(&(market_id, subaccount_id))
+    }
+}
+
+impl HandlesTraderSpotOrdersToCancelUpToAmountQuery for KeyedHandler<(MarketId, SubaccountId)> {
+    fn handle(
+        &self,
+        market_id: MarketId,
+        subaccount_id: SubaccountId,
+        _: FPDecimal,
+        _: FPDecimal,
+        _: CancellationStrategy,
+        _: Option<FPDecimal>,
+    ) -> QuerierResult {
+        self.respond(&(market_id, subaccount_id))
+    }
+}
+
+impl HandlesTraderDerivativeOrdersToCancelUpToAmountQuery for KeyedHandler<(MarketId, SubaccountId)> {
+    fn handle(&self, market_id: MarketId, subaccount_id: SubaccountId, _: FPDecimal, _: CancellationStrategy, _: Option<FPDecimal>) -> QuerierResult {
+        self.respond(&(market_id, subaccount_id))
+    }
+}
+
+impl HandlesSubaccountAndDenomQuery for KeyedHandler<(SubaccountId, String)> {
+    fn handle(&self, subaccount_id: SubaccountId, denom: String) -> QuerierResult {
+        self.respond(&(subaccount_id, denom))
+    }
+}
+
+impl HandlesDenomDecimalQuery for KeyedHandler<String> {
+    fn handle(&self, denom: String) -> QuerierResult {
+        self.respond(&denom)
+    }
+}
+
+impl HandlesDenomSupplyQuery for KeyedHandler<String> {
+    fn handle(&self, denom: String) -> QuerierResult {
+        self.respond(&denom)
+    }
+}
+
+impl HandlesPythPriceQuery for KeyedHandler<String> {
+    fn handle(&self, price_id: String) -> QuerierResult {
+        self.respond(&price_id)
+    }
+}
+
+impl HandlesByAddressQuery for KeyedHandler<String> {
+    fn handle(&self, address: String) -> QuerierResult {
+        self.respond(&address)
+    }
+}
+
+impl HandlesAccountVolumeQuery for KeyedHandler<String> {
+    fn handle(&self, account: String) -> QuerierResult {
+        self.respond(&account)
+    }
+}
+
+impl HandlesBankAllBalancesQuery for KeyedHandler<String> {
+    fn handle(&self, address: String) -> QuerierResult {
+        self.respond(&address)
+    }
+}
+
+impl HandlesAddressGrantsQuery for KeyedHandler<String> {
+    fn handle(&self, address: String, _: Option<PageRequest>) -> QuerierResult {
+        self.respond(&address)
+    }
+}
+
+/// Keyed by contract address, whatever the query message
+impl HandlesSmartQuery for KeyedHandler<String> {
+    fn handle(&self, contract_addr: &str, _: &Binary) -> QuerierResult {
+        self.respond(&contract_addr.to_string())
+    }
+}
+
+/// Keyed by contract address, whatever the storage key
+impl HandlesRawQuery for KeyedHandler<String> {
+    fn handle(&self, contract_addr: &str, _: &Binary) -> QuerierResult {
+        self.respond(&contract_addr.to_string())
+    }
+}
+
+impl HandlesContractInfo for KeyedHandler<String> {
+    fn handle(&self, contract_addr: &str) -> QuerierResult {
+        self.respond(&contract_addr.to_string())
+    }
+}
+
+impl HandlesStakedAmountQuery for KeyedHandler<Addr> {
+    fn handle(&self, delegator_address: Addr, _: u16) -> QuerierResult {
+        self.respond(&delegator_address)
+    }
+}
+
+impl HandlesCodeInfo for KeyedHandler<u64> {
+    fn handle(&self, code_id: u64) -> QuerierResult {
+        self.respond(&code_id)
+    }
+}
+
+impl HandlesBankBalanceQuery for KeyedHandler<(String, String)> {
+    fn handle(&self, address: String, denom: String) -> QuerierResult {
+        self.respond(&(address, denom))
+    }
+}
+
+impl HandlesDenomAuthorityMetadataQuery for KeyedHandler<(String, String)> {
+    fn handle(&self, creator: String, sub_denom: String) -> QuerierResult {
+        self.respond(&(creator, sub_denom))
+    }
+}
+
+/// Keyed by (base, quote), whatever the oracle type
+impl HandlesOraclePriceQuery for KeyedHandler<(String, String)> {
+    fn handle(&self, _: OracleType, base: String, quote: String) -> QuerierResult {
+        self.respond(&(base, quote))
+    }
+}
+
+/// Keyed by the base symbol, the quote symbol if any and the base oracle type, whatever the history options.
+/// Queries without a base oracle info get a `SystemError`, the chain rejects them too.
+impl HandlesOracleVolatilityQuery for KeyedHandler<(String, Option<String>, OracleType)> {
+    fn handle(&self, base_info: Option<OracleInfo>, quote_info: Option<OracleInfo>, _: Option<OracleHistoryOptions>) -> QuerierResult {
+        match base_info {
+            Some(base_info) => self.respond(&(base_info.symbol, quote_info.map(|info| info.symbol), base_info.oracle_type)),
+            None => SystemResult::Err(SystemError::UnsupportedRequest {
+                kind: "oracle volatility query without a base oracle info".to_string(),
+            }),
+        }
+    }
+}
+
+impl HandlesGrantsQuery for KeyedHandler<(String, String, String)> {
+    fn handle(&self, granter: String, grantee: String, msg_type_url: String, _: Option<PageRequest>) -> QuerierResult {
+        self.respond(&(granter, grantee, msg_type_url))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{SystemError, SystemResult};
+    use injective_math::FPDecimal;
+
+    use crate::exchange_mock_keyed::KeyedHandler;
+    use crate::exchange_mock_querier::mock_dependencies;
+    use crate::querier::InjectiveQuerier;
+    use crate::test_helpers::testing_helpers::create_mock_spot_market;
+    use crate::{Deposit, MarketId, OracleInfo, OracleType, OracleVolatilityResponse, SpotMarketResponse, SubaccountDepositResponse, SubaccountId};
+
+    const SUBACCOUNT: &str = "0xaf79152ac5df276d9a8e1e2e22822f9713474902000000000000000000000000";
+
+    #[test]
+    fn responses_are_picked_by_key_with_fallback() {
+        let inj_market = create_mock_spot_market("inj", 0);
+        let atom_market = create_mock_spot_market("atom", 1);
+        let unknown_market = MarketId::unchecked("0x0000000000000000000000000000000000000000000000000000000000000000");
+        let subaccount_id = SubaccountId::unchecked(SUBACCOUNT);
+        let deposit = Deposit {
+            available_balance: FPDecimal::from(3u128),
+            total_balance: FPDecimal::from(4u128),
+        };
+
+        let mut deps = mock_dependencies();
+        deps.querier.spot_market_response_handler = Some(Box::new(
+            KeyedHandler::new()
+                .with(
+                    inj_market.market_id.clone(),
+                    &SpotMarketResponse {
+                        market: Some(inj_market.clone()),
+                    },
+                )
+                .with(
+                    atom_market.market_id.clone(),
+                    &SpotMarketResponse {
+                        market: Some(atom_market.clone()),
+                    },
+                )
+                .otherwise(&SpotMarketResponse { market: None }),
+        ));
+        deps.querier.subaccount_deposit_response_handler = Some(Box::new(KeyedHandler::new().with(
+            (subaccount_id.clone(), "usdt".to_string()),
+            &SubaccountDepositResponse { deposits: deposit.clone() },
+        )));
+
+        let deps = deps.as_ref();
+        let querier = InjectiveQuerier::new(&deps.querier);
+
+        assert_eq!(querier.query_spot_market(&inj_market.market_id).unwrap().market, Some(inj_market));
+        assert_eq!(querier.query_spot_market(&atom_market.market_id).unwrap().market, Some(atom_market));
+        assert_eq!(querier.query_spot_market(&unknown_market).unwrap().market, None);
+        assert_eq!(querier.query_subaccount_deposit(&subaccount_id, &"usdt").unwrap().deposits, deposit);
+        assert!(querier.query_subaccount_deposit(&subaccount_id, &"inj").is_err());
+    }
+
+    #[test]
+    fn missing_keys_without_fallback_are_reported() {
+        let handler: KeyedHandler<String> = KeyedHandler::new().with("usdt".to_string(), &6u64);
+
+        match handler.respond(&"inj".to_string()) {
+            SystemResult::Err(SystemError::UnsupportedRequest { kind }) => assert!(kind.contains("inj")),
+            _ => panic!("expected an unsupported request error"),
+        }
+    }
+
+    #[test]
+    fn oracle_volatility_is_keyed_by_symbols_and_oracle_type() {
+        let oracle_info = |symbol: &str, oracle_type| OracleInfo {
+            symbol: symbol.to_string(),
+            oracle_type,
+            scale_factor: 6,
+        };
+        let volatility = |value: u128| OracleVolatilityResponse {
+            volatility: Some(FPDecimal::from(value)),
+            history_metadata: None,
+            raw_history: None,
+        };
+
+        let mut deps = mock_dependencies();
+        deps.querier.oracle_volatility_response_handler = Some(Box::new(
+            KeyedHandler::new()
+                .with(("inj".to_string(), Some("usdt".to_string()), OracleType::PriceFeed), &volatility(2))
+                .with(("inj".to_string(), None, OracleType::Pyth), &volatility(3)),
+        ));
+        let deps = deps.as_ref();
+        let querier = InjectiveQuerier::new(&deps.querier);
+
+        let inj_price_feed = Some(oracle_info("inj", OracleType::PriceFeed));
+        let usdt_price_feed = Some(oracle_info("usdt", OracleType::PriceFeed));
+        let inj_pyth = Some(oracle_info("inj", OracleType::Pyth));
+
+        assert_eq!(
+            querier
+                .query_oracle_volatility(&inj_price_feed, &usdt_price_feed, 3600, false, false)
+                .unwrap()
+                .volatility,
+            Some(FPDecimal::from(2u128))
+        );
+        assert_eq!(
+            querier.query_oracle_volatility(&inj_pyth, &None, 3600, false, false).unwrap().volatility,
+            Some(FPDecimal::from(3u128))
+        );
+        assert!(querier.query_oracle_volatility(&inj_pyth, &usdt_price_feed, 3600, false, false).is_err());
+        assert!(querier.query_oracle_volatility(&None, &usdt_price_feed, 3600, false, false).is_err());
+    }
+}
@@ -1,3 +1,5 @@
+use std::cell::RefCell;
+use std::collections::BTreeMap;
 use std::marker::PhantomData;
 use std::str::FromStr;
 
@@ -9,22 +11,39 @@ use cosmwasm_std::{
 
 use injective_math::FPDecimal;
 
+use crate::auction::{
+    response::{AuctionParamsResponse, CurrentAuctionBasketResponse},
+    types::AuctionParams,
+};
+use crate::authz::{
+    response::{GranteeGrantsResponse, GranterGrantsResponse, GrantsResponse},
+    types::PageRequest,
+};
 use crate::exchange::{
+    binary_options_market::BinaryOptionsMarket,
     derivative_market::DerivativeMarket,
-    response::QueryOrderbookResponse,
-    types::{AtomicMarketOrderAccessLevel, MarketVolume, Params, PriceLevel, VolumeByType},
+    response::{QueryOrderbookResponse, SubaccountDepositsResponse, SubaccountPositionsResponse, SubaccountTradeNonceResponse},
+    types::{AtomicMarketOrderAccessLevel, DenomDecimals, MarketVolume, Params, PriceLevel, VolumeByType},
 };
+use crate::insurance::response::InsuranceFundResponse;
 use crate::oracle::{
     types::{OracleHistoryOptions, OracleType, PriceState, PythPriceState},
     volatility::TradeHistoryOptions,
 };
 
-use crate::tokenfactory::response::{TokenFactoryCreateDenomFeeResponse, TokenFactoryDenomSupplyResponse};
+use crate::tokenfactory::{
+    response::{
+        TokenFactoryCreateDenomFeeResponse, TokenFactoryDenomAuthorityMetadataResponse, TokenFactoryDenomSupplyResponse,
+        TokenFactoryDenomsFromCreatorResponse,
+    },
+    types::DenomAuthorityMetadata,
+};
 use crate::wasmx::response::QueryContractRegistrationInfoResponse;
 use crate::{
-    CancellationStrategy, Deposit, DerivativeMarketResponse, ExchangeParamsResponse, FullDerivativeMarket, InjectiveQuery, InjectiveQueryWrapper,
-    MarketMidPriceAndTOBResponse, MarketStatus, MarketVolatilityResponse, OracleInfo, OracleVolatilityResponse, OrderSide,
-    PerpetualMarketFundingResponse, PerpetualMarketInfoResponse, PythPriceResponse, QueryAggregateMarketVolumeResponse, QueryAggregateVolumeResponse,
+    BinaryOptionsMarketResponse, CancellationStrategy, Deposit, DerivativeMarketResponse, ExchangeParamsResponse, ExpiryFuturesMarketInfoResponse,
+    FullDerivativeMarket, InjectiveQuery, InjectiveQueryWrapper, MarketMidPriceAndTOBResponse, MarketStatus, MarketVolatilityResponse, OracleInfo,
+    OracleVolatilityResponse, OrderSide, PerpetualMarketFundingResponse, PerpetualMarketInfoResponse, PythPriceResponse,
+    QueryAggregateMarketVolumeResponse, QueryAggregateVolumeResponse, QueryDenomDecimalResponse, QueryDenomDecimalsResponse,
     QueryMarketAtomicExecutionFeeMultiplierResponse, SpotMarket, SpotMarketResponse, SubaccountDepositResponse,
     SubaccountEffectivePositionInMarketResponse, SubaccountPositionInMarketResponse, TraderDerivativeOrdersResponse, TraderSpotOrdersResponse,
 };
@@ -52,6 +71,16 @@ fn default_subaccount_deposit_response_handler() -> QuerierResult {
     SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
 }
 
+fn default_subaccount_deposits_response_handler() -> QuerierResult {
+    let response = SubaccountDepositsResponse { deposits: BTreeMap::new() };
+    SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
+}
+
+fn default_subaccount_trade_nonce_response_handler() -> QuerierResult {
+    let response = SubaccountTradeNonceResponse { nonce: 0 };
+    SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
+}
+
 fn default_spot_market_response_handler(market_id: MarketId) -> QuerierResult {
     let response = SpotMarketResponse {
         market: Some(SpotMarket {
@@ -112,8 +141,34 @@ fn default_derivative_market_response_handler(market_id: MarketId) -> QuerierRes
     SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
 }
 
+fn default_binary_options_market_response_handler(market_id: MarketId) -> QuerierResult {
+    let response = BinaryOptionsMarketResponse {
+        market: Some(BinaryOptionsMarket {
+            ticker: "ticker".to_string(),
+            oracle_symbol: "oracle_symbol".to_string(),
+            oracle_provider: "oracle_provider".to_string(),
+            oracle_type: OracleType::Provider,
+            oracle_scale_factor: 6,
+            expiration_timestamp: 1_700_000_000i64,
+            settlement_timestamp: 1_700_003_600i64,
+            admin: "inj1k2z3chspuk9wsufle69svmtmnlc07rvw9djya7".to_string(),
+            quote_denom: "usdt".to_string(),
+            market_id,
+            maker_fee_rate: FPDecimal::from_str("0.001").unwrap(),
+            taker_fee_rate: FPDecimal::from_str("0.002").unwrap(),
+            relayer_fee_share_rate: FPDecimal::from_str("0.4").unwrap(),
+            status: MarketStatus::Active,
+            min_price_tick_size: FPDecimal::from_str("10000.0").unwrap(),
+            min_quantity_tick_size: FPDecimal::from_str("1.0").unwrap(),
+            settlement_price: None,
+        }),
+    };
+    SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
+}
+
 fn default_subaccount_positions_response_handler() -> QuerierResult {
-    todo!()
+    let response = SubaccountPositionsResponse { state: vec![] };
+    SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
 }
 
 fn default_subaccount_position_in_market_response_handler() -> QuerierResult {
@@ -146,6 +201,11 @@ fn default_perpetual_market_info_response_handler() -> QuerierResult {
     SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
 }
 
+fn default_expiry_futures_market_info_response_handler() -> QuerierResult {
+    let response = ExpiryFuturesMarketInfoResponse { info: None };
+    SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
+}
+
 fn default_perpetual_market_funding_response_handler() -> QuerierResult {
     let response = PerpetualMarketFundingResponse { state: None };
     SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
@@ -259,6 +319,18 @@ fn default_token_factory_denom_creation_fee_handler() -> QuerierResult {
     SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
 }
 
+fn default_token_factory_denom_authority_metadata_handler() -> QuerierResult {
+    let response = TokenFactoryDenomAuthorityMetadataResponse {
+        authority_metadata: DenomAuthorityMetadata { admin: String::new() },
+    };
+    SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
+}
+
+fn default_token_factory_denoms_from_creator_handler() -> QuerierResult {
+    let response = TokenFactoryDenomsFromCreatorResponse { denoms: vec![] };
+    SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
+}
+
 fn default_contract_registration_info_response_handler() -> QuerierResult {
     let response = QueryContractRegistrationInfoResponse { contract: None };
     SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
@@ -341,6 +413,68 @@ fn default_market_atomic_execution_fee_multiplier_response_handler() -> QuerierR
     SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
 }
 
+fn default_denom_decimal_handler() -> QuerierResult {
+    let response = QueryDenomDecimalResponse { decimals: 6 };
+    SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
+}
+
+fn default_denom_decimals_handler(denoms: Vec<String>) -> QuerierResult {
+    let response = QueryDenomDecimalsResponse {
+        denom_decimals: denoms.into_iter().map(|denom| DenomDecimals { denom, decimals: 6 }).collect(),
+    };
+    SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
+}
+
+fn default_auction_params_response_handler() -> QuerierResult {
+    let response = AuctionParamsResponse {
+        params: AuctionParams {
+            auction_period: 604_800,
+            min_next_bid_increment_rate: FPDecimal::from_str("0.0025").unwrap(),
+        },
+    };
+    SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
+}
+
+fn default_current_auction_basket_response_handler() -> QuerierResult {
+    let response = CurrentAuctionBasketResponse {
+        amount: vec![],
+        auction_round: 1,
+        auction_closing_time: 0,
+        highest_bidder: "".to_string(),
+        highest_bid_amount: Uint128::zero(),
+    };
+    SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
+}
+
+fn default_insurance_fund_response_handler() -> QuerierResult {
+    let response = InsuranceFundResponse { fund: None };
+    SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
+}
+
+fn default_grants_response_handler() -> QuerierResult {
+    let response = GrantsResponse {
+        grants: Some(vec![]),
+        pagination: None,
+    };
+    SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
+}
+
+fn default_granter_grants_response_handler() -> QuerierResult {
+    let response = GranterGrantsResponse {
+        grants: Some(vec![]),
+        pagination: None,
+    };
+    SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
+}
+
+fn default_grantee_grants_response_handler() -> QuerierResult {
+    let response = GranteeGrantsResponse {
+        grants: Some(vec![]),
+        pagination: None,
+    };
+    SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
+}
+
 pub trait HandlesSmartQuery {
     fn handle(&self, contract_addr: &str, msg: &Binary) -> QuerierResult;
 }
@@ -433,6 +567,14 @@ pub trait HandlesFeeQuery {
     fn handle(&self) -> QuerierResult;
 }
 
+pub trait HandlesAuctionQuery {
+    fn handle(&self) -> QuerierResult;
+}
+
+pub trait HandlesDenomAuthorityMetadataQuery {
+    fn handle(&self, creator: String, sub_denom: String) -> QuerierResult;
+}
+
 pub trait HandlesBankBalanceQuery {
     fn handle(&self, address: String, denom: String) -> QuerierResult;
 }
@@ -473,18 +615,29 @@ pub trait HandlesExchangeParamsQuery {
     fn handle(&self) -> QuerierResult;
 }
 
+pub trait HandlesGrantsQuery {
+    fn handle(&self, granter: String, grantee: String, msg_type_url: String, pagination: Option<PageRequest>) -> QuerierResult;
+}
+
+pub trait HandlesAddressGrantsQuery {
+    fn handle(&self, address: String, pagination: Option<PageRequest>) -> QuerierResult;
+}
+
 pub struct WasmMockQuerier {
     pub smart_query_handler: Option<Box<dyn HandlesSmartQuery>>,
     pub raw_query_handler: Option<Box<dyn HandlesRawQuery>>,
     pub contract_info_handler: Option<Box<dyn HandlesContractInfo>>,
     pub code_info_handler: Option<Box<dyn HandlesCodeInfo>>,
     pub subaccount_deposit_response_handler: Option<Box<dyn HandlesSubaccountAndDenomQuery>>,
+    pub subaccount_deposits_response_handler: Option<Box<dyn HandlesSubaccountIdQuery>>,
+    pub subaccount_trade_nonce_response_handler: Option<Box<dyn HandlesSubaccountIdQuery>>,
     pub exchange_params_response_handler: Option<Box<dyn HandlesExchangeParamsQuery>>,
     pub spot_market_response_handler: Option<Box<dyn HandlesMarketIdQuery>>,
     pub trader_spot_orders_response_handler: Option<Box<dyn HandlesMarketAndSubaccountQuery>>,
     pub trader_spot_orders_to_cancel_up_to_amount_response_handler: Option<Box<dyn HandlesTraderSpotOrdersToCancelUpToAmountQuery>>,
     pub trader_derivative_orders_to_cancel_up_to_amount_response_handler: Option<Box<dyn HandlesTraderDerivativeOrdersToCancelUpToAmountQuery>>,
     pub derivative_market_response_handler: Option<Box<dyn HandlesMarketIdQuery>>,
+    pub binary_options_market_response_handler: Option<Box<dyn HandlesMarketIdQuery>>,
     pub subaccount_positions_response_handler: Option<Box<dyn HandlesSubaccountIdQuery>>,
     pub subaccount_position_in_market_response_handler: Option<Box<dyn HandlesMarketAndSubaccountQuery>>,
     pub subaccount_effective_position_in_market_response_handler: Option<Box<dyn HandlesMarketAndSubaccountQuery>>,
@@ -493,6 +646,7 @@ pub struct WasmMockQuerier {
     pub trader_transient_derivative_orders_response_handler: Option<Box<dyn HandlesMarketAndSubaccountQuery>>,
     pub perpetual_market_info_response_handler: Option<Box<dyn HandlesMarketIdQuery>>,
     pub perpetual_market_funding_response_handler: Option<Box<dyn HandlesMarketIdQuery>>,
+    pub expiry_futures_market_info_response_handler: Option<Box<dyn HandlesMarketIdQuery>>,
     pub market_volatility_response_handler: Option<Box<dyn HandlesMarketVolatilityQuery>>,
     pub spot_market_mid_price_and_tob_response_handler: Option<Box<dyn HandlesMarketIdQuery>>,
     pub derivative_market_mid_price_and_tob_response_handler: Option<Box<dyn HandlesMarketIdQuery>>,
@@ -506,13 +660,22 @@ pub struct WasmMockQuerier {
     pub pyth_price_response_handler: Option<Box<dyn HandlesPythPriceQuery>>,
     pub token_factory_denom_total_supply_handler: Option<Box<dyn HandlesDenomSupplyQuery>>,
     pub token_factory_denom_creation_fee_handler: Option<Box<dyn HandlesFeeQuery>>,
+    pub token_factory_denom_authority_metadata_handler: Option<Box<dyn HandlesDenomAuthorityMetadataQuery>>,
+    pub token_factory_denoms_from_creator_handler: Option<Box<dyn HandlesByAddressQuery>>,
     pub balance_query_handler: Option<Box<dyn HandlesBankBalanceQuery>>,
     pub all_balances_query_handler: Option<Box<dyn HandlesBankAllBalancesQuery>>,
     pub total_supply_handler: Option<Box<dyn HandlesDenomSupplyQuery>>,
     pub registered_contract_info_query_handler: Option<Box<dyn HandlesByAddressQuery>>,
+    pub auction_params_response_handler: Option<Box<dyn HandlesAuctionQuery>>,
+    pub current_auction_basket_response_handler: Option<Box<dyn HandlesAuctionQuery>>,
+    pub insurance_fund_response_handler: Option<Box<dyn HandlesMarketIdQuery>>,
     pub spot_market_orderbook_response_handler: Option<Box<dyn HandlesPriceLevelsQuery>>,
     pub derivative_market_orderbook_response_handler: Option<Box<dyn HandlesDerivativePriceLevelsQuery>>,
     pub market_atomic_execution_fee_multiplier_response_handler: Option<Box<dyn HandlesMarketIdQuery>>,
+    pub grants_response_handler: Option<Box<dyn HandlesGrantsQuery>>,
+    pub granter_grants_response_handler: Option<Box<dyn HandlesAddressGrantsQuery>>,
+    pub grantee_grants_response_handler: Option<Box<dyn HandlesAddressGrantsQuery>>,
+    pub query_log: QueryLog,
 }
 
 impl Querier for WasmMockQuerier {
@@ -533,25 +696,27 @@ impl Querier for WasmMockQuerier {
 
 impl WasmMockQuerier {
     pub fn handle_query(&self, request: &QueryRequest<InjectiveQueryWrapper>) -> QuerierResult {
+        self.query_log.record(request);
+
         match &request {
             QueryRequest::Wasm(query) => match query {
                 WasmQuery::Smart { contract_addr, msg } => match &self.smart_query_handler {
                     Some(handler) => handler.handle(contract_addr, msg),
-                    None => panic!("Unknown smart query"),
+                    None => unsupported_request("smart query"),
                 },
                 WasmQuery::Raw { contract_addr, key } => match &self.raw_query_handler {
                     Some(handler) => handler.handle(contract_addr, key),
-                    None => panic!("Unknown raw query"),
+                    None => unsupported_request("raw query"),
                 },
                 WasmQuery::CodeInfo { code_id } => match &self.code_info_handler {
                     Some(handler) => handler.handle(*code_id),
-                    None => panic!("Unknown code info query"),
+                    None => unsupported_request("code info query"),
                 },
                 WasmQuery::ContractInfo { contract_addr } => match &self.contract_info_handler {
                     Some(handler) => handler.handle(contract_addr),
-                    None => panic!("Unknown contract info query"),
+                    None => unsupported_request("contract info query"),
                 },
-                _ => panic!("unsupported"),
+                _ => unsupported_request("wasm query"),
             },
             QueryRequest::Bank(query) => match query {
                 BankQuery::Balance { address, denom } => match &self.balance_query_handler {
@@ -566,13 +731,21 @@ impl WasmMockQuerier {
                     Some(handler) => handler.handle(denom.to_string()),
                     None => default_bank_total_supply_handler(),
                 },
-                _ => panic!("unsupported"),
+                _ => unsupported_request("bank query"),
             },
             QueryRequest::Custom(query) => match query.query_data.clone() {
                 InjectiveQuery::SubaccountDeposit { subaccount_id, denom } => match &self.subaccount_deposit_response_handler {
                     Some(handler) => handler.handle(subaccount_id, denom),
                     None => default_subaccount_deposit_response_handler(),
                 },
+                InjectiveQuery::SubaccountTradeNonce { subaccount_id } => match &self.subaccount_trade_nonce_response_handler {
+                    Some(handler) => handler.handle(subaccount_id),
+                    None => default_subaccount_trade_nonce_response_handler(),
+                },
+                InjectiveQuery::SubaccountDeposits { subaccount_id } => match &self.subaccount_deposits_response_handler {
+                    Some(handler) => handler.handle(subaccount_id),
+                    None => default_subaccount_deposits_response_handler(),
+                },
                 InjectiveQuery::ExchangeParams {} => match &self.exchange_params_response_handler {
                     Some(handler) => handler.handle(),
                     None => default_exchange_params_response_handler(),
@@ -610,6 +783,10 @@ impl WasmMockQuerier {
                     Some(handler) => handler.handle(market_id),
                     None => default_derivative_market_response_handler(market_id),
                 },
+                InjectiveQuery::BinaryOptionsMarket { market_id } => match &self.binary_options_market_response_handler {
+                    Some(handler) => handler.handle(market_id),
+                    None => default_binary_options_market_response_handler(market_id),
+                },
                 InjectiveQuery::SubaccountPositions { subaccount_id } => match &self.subaccount_positions_response_handler {
                     Some(handler) => handler.handle(subaccount_id),
                     None => default_subaccount_positions_response_handler(),
@@ -648,6 +825,10 @@ impl WasmMockQuerier {
                     Some(handler) => handler.handle(market_id),
                     None => default_perpetual_market_funding_response_handler(),
                 },
+                InjectiveQuery::ExpiryFuturesMarketInfo { market_id } => match &self.expiry_futures_market_info_response_handler {
+                    Some(handler) => handler.handle(market_id),
+                    None => default_expiry_futures_market_info_response_handler(),
+                },
                 InjectiveQuery::MarketVolatility {
                     market_id,
                     trade_history_options,
@@ -702,6 +883,28 @@ impl WasmMockQuerier {
                     Some(handler) => handler.handle(),
                     None => default_token_factory_denom_creation_fee_handler(),
                 },
+                InjectiveQuery::TokenFactoryDenomAuthorityMetadata { creator, sub_denom } => {
+                    match &self.token_factory_denom_authority_metadata_handler {
+                        Some(handler) => handler.handle(creator, sub_denom),
+                        None => default_token_factory_denom_authority_metadata_handler(),
+                    }
+                }
+                InjectiveQuery::TokenFactoryDenomsFromCreator { creator } => match &self.token_factory_denoms_from_creator_handler {
+                    Some(handler) => handler.handle(creator),
+                    None => default_token_factory_denoms_from_creator_handler(),
+                },
+                InjectiveQuery::AuctionParams {} => match &self.auction_params_response_handler {
+                    Some(handler) => handler.handle(),
+                    None => default_auction_params_response_handler(),
+                },
+                InjectiveQuery::CurrentAuctionBasket {} => match &self.current_auction_basket_response_handler {
+                    Some(handler) => handler.handle(),
+                    None => default_current_auction_basket_response_handler(),
+                },
+                InjectiveQuery::InsuranceFund { market_id } => match &self.insurance_fund_response_handler {
+                    Some(handler) => handler.handle(market_id),
+                    None => default_insurance_fund_response_handler(),
+                },
                 InjectiveQuery::WasmxRegisteredContractInfo { contract_address } => match &self.registered_contract_info_query_handler {
                     Some(handler) => handler.handle(contract_address),
                     None => default_contract_registration_info_response_handler(),
@@ -720,8 +923,33 @@ impl WasmMockQuerier {
                         None => default_market_atomic_execution_fee_multiplier_response_handler(),
                     }
                 }
+                InjectiveQuery::DenomDecimal { denom } => match &self.denom_decimal_handler {
+                    Some(handler) => handler.handle(denom),
+                    None => default_denom_decimal_handler(),
+                },
+                InjectiveQuery::DenomDecimals { denoms } => match &self.denom_decimals_handler {
+                    Some(handler) => handler.handle(denoms),
+                    None => default_denom_decimals_handler(denoms),
+                },
+                InjectiveQuery::Grants {
+                    granter,
+                    grantee,
+                    msg_type_url,
+                    pagination,
+                } => match &self.grants_response_handler {
+                    Some(handler) => handler.handle(granter, grantee, msg_type_url, pagination),
+                    None => default_grants_response_handler(),
+                },
+                InjectiveQuery::GranterGrants { granter, pagination } => match &self.granter_grants_response_handler {
+                    Some(handler) => handler.handle(granter, pagination),
+                    None => default_granter_grants_response_handler(),
+                },
+                InjectiveQuery::GranteeGrants { grantee, pagination } => match &self.grantee_grants_response_handler {
+                    Some(handler) => handler.handle(grantee, pagination),
+                    None => default_grantee_grants_response_handler(),
+                },
             },
-            _ => panic!("Unknown query"),
+            _ => unsupported_request("query"),
         }
     }
 }
@@ -740,12 +968,15 @@ impl WasmMockQuerier {
             code_info_handler: None,
             contract_info_handler: None,
             subaccount_deposit_response_handler: None,
+            subaccount_deposits_response_handler: None,
+            subaccount_trade_nonce_response_handler: None,
             exchange_params_response_handler: None,
             spot_market_response_handler: None,
             trader_spot_orders_response_handler: None,
             trader_spot_orders_to_cancel_up_to_amount_response_handler: None,
             trader_derivative_orders_to_cancel_up_to_amount_response_handler: None,
             derivative_market_response_handler: None,
+            binary_options_market_response_handler: None,
             subaccount_positions_response_handler: None,
             subaccount_position_in_market_response_handler: None,
             subaccount_effective_position_in_market_response_handler: None,
@@ -754,6 +985,7 @@ impl WasmMockQuerier {
             trader_transient_derivative_orders_response_handler: None,
             perpetual_market_info_response_handler: None,
             perpetual_market_funding_response_handler: None,
+            expiry_futures_market_info_response_handler: None,
             market_volatility_response_handler: None,
             spot_market_mid_price_and_tob_response_handler: None,
             derivative_market_mid_price_and_tob_response_handler: None,
@@ -766,16 +998,92 @@ impl WasmMockQuerier {
             pyth_price_response_handler: None,
             token_factory_denom_total_supply_handler: None,
             token_factory_denom_creation_fee_handler: None,
+            token_factory_denom_authority_metadata_handler: None,
+            token_factory_denoms_from_creator_handler: None,
             balance_query_handler: None,
             all_balances_query_handler: None,
             registered_contract_info_query_handler: None,
+            auction_params_response_handler: None,
+            current_auction_basket_response_handler: None,
+            insurance_fund_response_handler: None,
             denom_decimals_handler: None,
             spot_market_orderbook_response_handler: None,
             derivative_market_orderbook_response_handler: None,
             market_atomic_execution_fee_multiplier_response_handler: None,
             total_supply_handler: None,
+            grants_response_handler: None,
+            granter_grants_response_handler: None,
+            grantee_grants_response_handler: None,
+            query_log: QueryLog::default(),
         }
     }
+
+    #[track_caller]
+    pub fn assert_queried_times(&self, query: &InjectiveQuery, times: usize) {
+        let count = self.query_log.count(query);
+        assert_eq!(
+            count, times,
+            "expected {:?} to be queried {} times, it was queried {} times",
+            query, times, count
+        );
+    }
+
+    #[track_caller]
+    pub fn assert_queried_once(&self, query: &InjectiveQuery) {
+        self.assert_queried_times(query, 1);
+    }
+
+    /// e.g. `assert_never_queried(|query| matches!(query, InjectiveQuery::MarketVolatility { .. }))`
+    #[track_caller]
+    pub fn assert_never_queried(&self, predicate: impl Fn(&InjectiveQuery) -> bool) {
+        let matching: Vec<InjectiveQuery> = self.query_log.injective_queries().into_iter().filter(|query| predicate(query)).collect();
+        assert!(matching.is_empty(), "expected no matching queries, got {:?}", matching);
+    }
+}
+
+/// Requests received by a `WasmMockQuerier`, in order, including the ones no handler answered
+#[derive(Default)]
+pub struct QueryLog {
+    requests: RefCell<Vec<QueryRequest<InjectiveQueryWrapper>>>,
+}
+
+impl QueryLog {
+    fn record(&self, request: &QueryRequest<InjectiveQueryWrapper>) {
+        self.requests.borrow_mut().push(request.clone());
+    }
+
+    pub fn requests(&self) -> Vec<QueryRequest<InjectiveQueryWrapper>> {
+        self.requests.borrow().clone()
+    }
+
+    pub fn injective_queries(&self) -> Vec<InjectiveQuery> {
+        let requests = self.requests.borrow();
+        requests
+            .iter()
+            .filter_map(|request| match request {
+                QueryRequest::Custom(query) => Some(query.query_data.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub fn count(&self, query: &InjectiveQuery) -> usize {
+        self.count_matching(|logged| logged == query)
+    }
+
+    pub fn count_matching(&self, predicate: impl Fn(&InjectiveQuery) -> bool) -> usize {
+        self.injective_queries().iter().filter(|query| predicate(query)).count()
+    }
+
+    pub fn clear(&self) {
+        self.requests.borrow_mut().clear();
+    }
+}
+
+fn unsupported_request(kind: &str) -> QuerierResult {
+    SystemResult::Err(SystemError::UnsupportedRequest {
+        kind: format!("{kind} without a configured handler"),
+    })
 }
 
 pub struct TestCoin {
@@ -809,23 +1117,39 @@ pub mod handlers {
 
     use injective_math::FPDecimal;
 
-    use crate::exchange::response::QueryOrderbookResponse;
-    use crate::exchange_mock_querier::{HandlesByAddressQuery, HandlesDenomSupplyQuery, HandlesFeeQuery};
+    use crate::auction::response::CurrentAuctionBasketResponse;
+    use crate::authz::{
+        response::{Grant, GrantAuthorization, GranteeGrantsResponse, GranterGrantsResponse, GrantsResponse},
+        types::PageRequest,
+    };
+    use crate::exchange::response::{QueryOrderbookResponse, SubaccountDepositsResponse, SubaccountPositionsResponse, SubaccountTradeNonceResponse};
+    use crate::exchange_mock_querier::{
+        HandlesAddressGrantsQuery, HandlesAuctionQuery, HandlesByAddressQuery, HandlesDenomAuthorityMetadataQuery, HandlesDenomDecimalQuery,
+        HandlesDenomDecimalsQuery, HandlesDenomSupplyQuery, HandlesFeeQuery, HandlesGrantsQuery, HandlesSubaccountIdQuery,
+    };
+    use crate::insurance::{response::InsuranceFundResponse, types::InsuranceFund};
     use crate::oracle::{response::OraclePriceResponse, types::PricePairState};
-    use crate::tokenfactory::response::{TokenFactoryCreateDenomFeeResponse, TokenFactoryDenomSupplyResponse};
+    use crate::tokenfactory::{
+        response::{
+            TokenFactoryCreateDenomFeeResponse, TokenFactoryDenomAuthorityMetadataResponse, TokenFactoryDenomSupplyResponse,
+            TokenFactoryDenomsFromCreatorResponse,
+        },
+        types::DenomAuthorityMetadata,
+    };
     use crate::wasmx::{response::QueryContractRegistrationInfoResponse, types::RegisteredContract};
     use crate::{
-        exchange_mock_querier::TestCoin, CancellationStrategy, Deposit, DerivativeMarket, DerivativeMarketResponse, EffectivePosition,
-        FullDerivativeMarket, FullDerivativeMarketPerpetualInfo, HandlesMarketAndSubaccountQuery, HandlesMarketIdQuery, HandlesOracleVolatilityQuery,
-        HandlesPriceLevelsQuery, HandlesRawQuery, HandlesSmartQuery, HandlesSubaccountAndDenomQuery, HandlesTraderSpotOrdersToCancelUpToAmountQuery,
-        MarketId, MetadataStatistics, OracleVolatilityResponse, OrderSide, Position, PriceLevel, QueryMarketAtomicExecutionFeeMultiplierResponse,
-        SpotMarket, SpotMarketResponse, SubaccountDepositResponse, SubaccountEffectivePositionInMarketResponse, SubaccountId,
-        SubaccountPositionInMarketResponse, TradeRecord, TraderDerivativeOrdersResponse, TraderSpotOrdersResponse, TrimmedDerivativeLimitOrder,
-        TrimmedSpotLimitOrder,
+        exchange_mock_querier::TestCoin, BinaryOptionsMarket, BinaryOptionsMarketResponse, CancellationStrategy, Deposit, DerivativeMarket,
+        DerivativeMarketResponse, EffectivePosition, ExpiryFuturesMarketInfo, ExpiryFuturesMarketInfoResponse, FullDerivativeMarket,
+        FullDerivativeMarketInfo, HandlesMarketAndSubaccountQuery, HandlesMarketIdQuery, HandlesOracleVolatilityQuery, HandlesPriceLevelsQuery,
+        HandlesRawQuery, HandlesSmartQuery, HandlesSubaccountAndDenomQuery, HandlesTraderSpotOrdersToCancelUpToAmountQuery, MarketId,
+        MetadataStatistics, OracleVolatilityResponse, OrderSide, Position, PriceLevel, QueryMarketAtomicExecutionFeeMultiplierResponse, SpotMarket,
+        SpotMarketResponse, SubaccountDepositResponse, SubaccountEffectivePositionInMarketResponse, SubaccountId, SubaccountPositionInMarketResponse,
+        TradeRecord, TraderDerivativeOrdersResponse, TraderSpotOrdersResponse, TrimmedDerivativeLimitOrder, TrimmedSpotLimitOrder,
     };
     use crate::{
-        HandlesBankAllBalancesQuery, HandlesBankBalanceQuery, HandlesCodeInfo, HandlesContractInfo,
-        HandlesTraderDerivativeOrdersToCancelUpToAmountQuery, MarketMidPriceAndTOBResponse, OracleType,
+        DenomDecimals, DerivativePosition, HandlesBankAllBalancesQuery, HandlesBankBalanceQuery, HandlesCodeInfo, HandlesContractInfo,
+        HandlesTraderDerivativeOrdersToCancelUpToAmountQuery, MarketMidPriceAndTOBResponse, OracleType, QueryDenomDecimalResponse,
+        QueryDenomDecimalsResponse,
     };
 
     use super::{HandlesOraclePriceQuery, TestDeposit};
@@ -879,6 +1203,57 @@ pub mod handlers {
         Some(Box::new(Temp { deposits }))
     }
 
+    pub fn create_subaccount_trade_nonce_handler(nonce: u32) -> Option<Box<dyn HandlesSubaccountIdQuery>> {
+        struct Temp {
+            nonce: u32,
+        }
+        impl HandlesSubaccountIdQuery for Temp {
+            fn handle(&self, _: SubaccountId) -> QuerierResult {
+                let response = SubaccountTradeNonceResponse { nonce: self.nonce };
+                SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
+            }
+        }
+        Some(Box::new(Temp { nonce }))
+    }
+
+    /// Subaccounts missing from `nonces` have not traded yet, so their nonce is zero
+    pub fn create_subaccount_trade_nonces_handler(nonces: HashMap<SubaccountId, u32>) -> Option<Box<dyn HandlesSubaccountIdQuery>> {
+        struct Temp {
+            nonces: HashMap<SubaccountId, u32>,
+        }
+        impl HandlesSubaccountIdQuery for Temp {
+            fn handle(&self, subaccount_id: SubaccountId) -> QuerierResult {
+                let response = SubaccountTradeNonceResponse {
+                    nonce: self.nonces.get(&subaccount_id).copied().unwrap_or_default(),
+                };
+                SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
+            }
+        }
+        Some(Box::new(Temp { nonces }))
+    }
+
+    /// Subaccounts missing from `deposits` have no deposits
+    pub fn create_subaccount_deposits_handler(deposits: HashMap<SubaccountId, Vec<TestDeposit>>) -> Option<Box<dyn HandlesSubaccountIdQuery>> {
+        struct Temp {
+            deposits: HashMap<SubaccountId, Vec<TestDeposit>>,
+        }
+        impl HandlesSubaccountIdQuery for Temp {
+            fn handle(&self, subaccount_id: SubaccountId) -> QuerierResult {
+                let response = SubaccountDepositsResponse {
+                    deposits: self
+                        .deposits
+                        .get(&subaccount_id)
+                        .into_iter()
+                        .flatten()
+                        .map(|d| (d.denom.to_owned(), d.deposit.to_owned()))
+                        .collect(),
+                };
+                SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
+            }
+        }
+        Some(Box::new(Temp { deposits }))
+    }
+
     pub fn create_subaccount_deposit_err_returning_handler() -> Option<Box<dyn HandlesSubaccountAndDenomQuery>> {
         struct A();
         impl HandlesSubaccountAndDenomQuery for A {
@@ -1007,12 +1382,12 @@ pub mod handlers {
 
     pub fn create_derivative_market_handler(
         market: Option<DerivativeMarket>,
-        info: Option<FullDerivativeMarketPerpetualInfo>,
+        info: Option<FullDerivativeMarketInfo>,
         mark_price: FPDecimal,
     ) -> Option<Box<dyn HandlesMarketIdQuery>> {
         struct Temp {
             market: Option<DerivativeMarket>,
-            info: Option<FullDerivativeMarketPerpetualInfo>,
+            info: Option<FullDerivativeMarketInfo>,
             mark_price: FPDecimal,
         }
         impl HandlesMarketIdQuery for Temp {
@@ -1030,6 +1405,36 @@ pub mod handlers {
         Some(Box::new(Temp { market, info, mark_price }))
     }
 
+    pub fn create_expiry_futures_market_info_handler(info: Option<ExpiryFuturesMarketInfo>) -> Option<Box<dyn HandlesMarketIdQuery>> {
+        struct Temp {
+            info: Option<ExpiryFuturesMarketInfo>,
+        }
+        impl HandlesMarketIdQuery for Temp {
+            fn handle(&self, market_id: MarketId) -> QuerierResult {
+                let response = ExpiryFuturesMarketInfoResponse {
+                    info: self.info.as_ref().filter(|info| info.market_id == market_id).cloned(),
+                };
+                SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
+            }
+        }
+        Some(Box::new(Temp { info }))
+    }
+
+    pub fn create_binary_options_market_handler(market: Option<BinaryOptionsMarket>) -> Option<Box<dyn HandlesMarketIdQuery>> {
+        struct Temp {
+            market: Option<BinaryOptionsMarket>,
+        }
+        impl HandlesMarketIdQuery for Temp {
+            fn handle(&self, market_id: MarketId) -> QuerierResult {
+                let response = BinaryOptionsMarketResponse {
+                    market: self.market.as_ref().filter(|market| market.market_id == market_id).cloned(),
+                };
+                SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
+            }
+        }
+        Some(Box::new(Temp { market }))
+    }
+
     pub fn create_trader_spot_orders_handler(orders: Option<Vec<TrimmedSpotLimitOrder>>) -> Option<Box<dyn HandlesMarketAndSubaccountQuery>> {
         struct Temp {
             orders: Option<Vec<TrimmedSpotLimitOrder>>,
@@ -1062,6 +1467,21 @@ pub mod handlers {
         Some(Box::new(Temp { orders }))
     }
 
+    pub fn create_subaccount_positions_handler(positions: Vec<DerivativePosition>) -> Option<Box<dyn HandlesSubaccountIdQuery>> {
+        struct Temp {
+            positions: Vec<DerivativePosition>,
+        }
+        impl HandlesSubaccountIdQuery for Temp {
+            fn handle(&self, subaccount_id: SubaccountId) -> QuerierResult {
+                let response = SubaccountPositionsResponse {
+                    state: self.positions.iter().filter(|p| p.subaccount_id == subaccount_id).cloned().collect(),
+                };
+                SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
+            }
+        }
+        Some(Box::new(Temp { positions }))
+    }
+
     pub fn create_subaccount_effective_position_in_market_handler(
         position: Option<EffectivePosition>,
     ) -> Option<Box<dyn HandlesMarketAndSubaccountQuery>> {
@@ -1241,6 +1661,40 @@ pub mod handlers {
         Some(Box::new(Temp { fee }))
     }
 
+    pub fn create_denom_authority_metadata_handler(admins: HashMap<String, String>) -> Option<Box<dyn HandlesDenomAuthorityMetadataQuery>> {
+        struct Temp {
+            admins: HashMap<String, String>,
+        }
+        impl HandlesDenomAuthorityMetadataQuery for Temp {
+            fn handle(&self, creator: String, sub_denom: String) -> QuerierResult {
+                let denom = format!("factory/{creator}/{sub_denom}");
+                let response = TokenFactoryDenomAuthorityMetadataResponse {
+                    authority_metadata: DenomAuthorityMetadata {
+                        admin: self.admins.get(&denom).cloned().unwrap_or_default(),
+                    },
+                };
+                SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
+            }
+        }
+        Some(Box::new(Temp { admins }))
+    }
+
+    pub fn create_denoms_from_creator_handler(denoms: Vec<String>) -> Option<Box<dyn HandlesByAddressQuery>> {
+        struct Temp {
+            denoms: Vec<String>,
+        }
+        impl HandlesByAddressQuery for Temp {
+            fn handle(&self, creator: String) -> QuerierResult {
+                let prefix = format!("factory/{creator}/");
+                let response = TokenFactoryDenomsFromCreatorResponse {
+                    denoms: self.denoms.iter().filter(|denom| denom.starts_with(&prefix)).cloned().collect(),
+                };
+                SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
+            }
+        }
+        Some(Box::new(Temp { denoms }))
+    }
+
     pub fn create_registered_contract_info_query_handler(contract: Option<RegisteredContract>) -> Option<Box<dyn HandlesByAddressQuery>> {
         struct Temp {
             contract: Option<RegisteredContract>,
@@ -1256,6 +1710,114 @@ pub mod handlers {
         Some(Box::new(Temp { contract }))
     }
 
+    pub fn create_current_auction_basket_handler(basket: CurrentAuctionBasketResponse) -> Option<Box<dyn HandlesAuctionQuery>> {
+        struct Temp {
+            basket: CurrentAuctionBasketResponse,
+        }
+        impl HandlesAuctionQuery for Temp {
+            fn handle(&self) -> QuerierResult {
+                SystemResult::Ok(ContractResult::from(to_json_binary(&self.basket)))
+            }
+        }
+        Some(Box::new(Temp { basket }))
+    }
+
+    pub fn create_insurance_fund_handler(funds: Vec<InsuranceFund>) -> Option<Box<dyn HandlesMarketIdQuery>> {
+        struct Temp {
+            funds: Vec<InsuranceFund>,
+        }
+        impl HandlesMarketIdQuery for Temp {
+            fn handle(&self, market_id: MarketId) -> QuerierResult {
+                let response = InsuranceFundResponse {
+                    fund: self.funds.iter().find(|fund| fund.market_id == market_id).cloned(),
+                };
+                SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
+            }
+        }
+        Some(Box::new(Temp { funds }))
+    }
+
+    pub fn create_grants_handler(grants: Vec<Grant>) -> Option<Box<dyn HandlesGrantsQuery>> {
+        struct Temp {
+            grants: Vec<Grant>,
+        }
+        impl HandlesGrantsQuery for Temp {
+            fn handle(&self, _: String, _: String, msg_type_url: String, _: Option<PageRequest>) -> QuerierResult {
+                let grants = self
+                    .grants
+                    .iter()
+                    .filter(|grant| msg_type_url.is_empty() || grant.authorization.msg_type_url() == Some(msg_type_url.as_str()))
+                    .cloned()
+                    .collect();
+                let response = GrantsResponse {
+                    grants: Some(grants),
+                    pagination: None,
+                };
+                SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
+            }
+        }
+        Some(Box::new(Temp { grants }))
+    }
+
+    /// `grants` are keyed by (granter, grantee), pairs missing from `grants` have no grants
+    pub fn create_grants_by_pair_handler(grants: HashMap<(String, String), Vec<Grant>>) -> Option<Box<dyn HandlesGrantsQuery>> {
+        struct Temp {
+            grants: HashMap<(String, String), Vec<Grant>>,
+        }
+        impl HandlesGrantsQuery for Temp {
+            fn handle(&self, granter: String, grantee: String, msg_type_url: String, _: Option<PageRequest>) -> QuerierResult {
+                let grants = self
+                    .grants
+                    .get(&(granter, grantee))
+                    .into_iter()
+                    .flatten()
+                    .filter(|grant| msg_type_url.is_empty() || grant.authorization.msg_type_url() == Some(msg_type_url.as_str()))
+                    .cloned()
+                    .collect();
+                let response = GrantsResponse {
+                    grants: Some(grants),
+                    pagination: None,
+                };
+                SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
+            }
+        }
+        Some(Box::new(Temp { grants }))
+    }
+
+    pub fn create_granter_grants_handler(grants: Vec<GrantAuthorization>) -> Option<Box<dyn HandlesAddressGrantsQuery>> {
+        struct Temp {
+            grants: Vec<GrantAuthorization>,
+        }
+        impl HandlesAddressGrantsQuery for Temp {
+            fn handle(&self, granter: String, _: Option<PageRequest>) -> QuerierResult {
+                let grants = self.grants.iter().filter(|grant| grant.granter == granter).cloned().collect();
+                let response = GranterGrantsResponse {
+                    grants: Some(grants),
+                    pagination: None,
+                };
+                SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
+            }
+        }
+        Some(Box::new(Temp { grants }))
+    }
+
+    pub fn create_grantee_grants_handler(grants: Vec<GrantAuthorization>) -> Option<Box<dyn HandlesAddressGrantsQuery>> {
+        struct Temp {
+            grants: Vec<GrantAuthorization>,
+        }
+        impl HandlesAddressGrantsQuery for Temp {
+            fn handle(&self, grantee: String, _: Option<PageRequest>) -> QuerierResult {
+                let grants = self.grants.iter().filter(|grant| grant.grantee == grantee).cloned().collect();
+                let response = GranteeGrantsResponse {
+                    grants: Some(grants),
+                    pagination: None,
+                };
+                SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
+            }
+        }
+        Some(Box::new(Temp { grants }))
+    }
+
     pub fn create_simple_balance_bank_query_handler(balances: Vec<Coin>) -> Option<Box<dyn HandlesBankBalanceQuery>> {
         struct Temp {
             balances: Vec<Coin>,
@@ -1302,6 +1864,48 @@ pub mod handlers {
         Some(Box::new(Temp { multiplier }))
     }
 
+    pub fn create_denom_decimal_handler(decimals: HashMap<String, u64>) -> Option<Box<dyn HandlesDenomDecimalQuery>> {
+        struct Temp {
+            decimals: HashMap<String, u64>,
+        }
+        impl HandlesDenomDecimalQuery for Temp {
+            fn handle(&self, denom: String) -> QuerierResult {
+                let response = QueryDenomDecimalResponse {
+                    decimals: self.decimals.get(&denom).copied().unwrap_or_default(),
+                };
+                SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
+            }
+        }
+        Some(Box::new(Temp { decimals }))
+    }
+
+    pub fn create_denom_decimals_handler(decimals: HashMap<String, u64>) -> Option<Box<dyn HandlesDenomDecimalsQuery>> {
+        struct Temp {
+            decimals: HashMap<String, u64>,
+        }
+        impl HandlesDenomDecimalsQuery for Temp {
+            fn handle(&self, denoms: Vec<String>) -> QuerierResult {
+                let denom_decimals = if denoms.is_empty() {
+                    self.decimals
+                        .iter()
+                        .map(|(denom, decimals)| DenomDecimals {
+                            denom: denom.to_owned(),
+                            decimals: *decimals,
+                        })
+                        .collect()
+                } else {
+                    denoms
+                        .into_iter()
+                        .filter_map(|denom| self.decimals.get(&denom).map(|decimals| DenomDecimals { denom, decimals: *decimals }))
+                        .collect()
+                };
+                let response = QueryDenomDecimalsResponse { denom_decimals };
+                SystemResult::Ok(ContractResult::from(to_json_binary(&response)))
+            }
+        }
+        Some(Box::new(Temp { decimals }))
+    }
+
     pub fn create_smart_query_handler(result: Result<Binary, SystemError>) -> Option<Box<dyn HandlesSmartQuery>> {
         struct Temp {
             result: Result<Binary, SystemError>,
@@ -1374,3 +1978,142 @@ pub mod handlers {
         }))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use cosmwasm_std::{to_json_binary, Empty, Querier, QueryRequest, SystemError, SystemResult, WasmQuery};
+
+    use crate::authz::{response::Grant, types::Authorization};
+    use crate::exchange::{
+        market::MarketStatus,
+        types::{MarketId, MarketType, SubaccountId},
+    };
+    use crate::exchange_mock_querier::{
+        handlers::{create_binary_options_market_handler, create_grants_by_pair_handler, create_subaccount_trade_nonces_handler},
+        mock_dependencies, WasmMockQuerier,
+    };
+    use crate::oracle::volatility::TradeHistoryOptions;
+    use crate::querier::InjectiveQuerier;
+    use crate::test_helpers::testing_helpers::create_mock_spot_market;
+    use crate::{InjectiveError, InjectiveQuery, InjectiveQueryWrapper, InjectiveRoute};
+
+    #[test]
+    fn unhandled_queries_return_an_error() {
+        let querier = WasmMockQuerier::new();
+        let request: QueryRequest<InjectiveQueryWrapper> = QueryRequest::Wasm(WasmQuery::Smart {
+            contract_addr: "contract".to_string(),
+            msg: to_json_binary(&Empty {}).unwrap(),
+        });
+
+        let result = querier.raw_query(&to_json_binary(&request).unwrap());
+
+        assert!(matches!(result, SystemResult::Err(SystemError::UnsupportedRequest { .. })));
+        assert_eq!(querier.query_log.requests(), vec![request]);
+    }
+
+    #[test]
+    fn queries_are_logged() {
+        let deps = mock_dependencies();
+        let market_id = create_mock_spot_market("inj", 0).market_id;
+
+        let deps_ref = deps.as_ref();
+        let querier = InjectiveQuerier::new(&deps_ref.querier);
+        querier.query_spot_market(&market_id).unwrap();
+        querier.query_derivative_market(&market_id).unwrap();
+
+        deps.querier.assert_queried_once(&InjectiveQuery::SpotMarket {
+            market_id: market_id.clone(),
+        });
+        querier.query_spot_market(&market_id).unwrap();
+        deps.querier.assert_queried_times(
+            &InjectiveQuery::SpotMarket {
+                market_id: market_id.clone(),
+            },
+            2,
+        );
+        deps.querier
+            .assert_never_queried(|query| matches!(query, InjectiveQuery::MarketVolatility { .. }));
+
+        let volatility = InjectiveQueryWrapper {
+            route: InjectiveRoute::Exchange,
+            query_data: InjectiveQuery::MarketVolatility {
+                market_id,
+                trade_history_options: TradeHistoryOptions {
+                    trade_grouping_sec: 0,
+                    max_age: 0,
+                    include_raw_history: false,
+                    include_metadata: false,
+                },
+            },
+        };
+        let _ = deps.querier.handle_query(&QueryRequest::Custom(volatility));
+        assert_eq!(
+            deps.querier
+                .query_log
+                .count_matching(|query| matches!(query, InjectiveQuery::MarketVolatility { .. })),
+            1
+        );
+    }
+
+    #[test]
+    fn binary_options_market_query() {
+        let mut deps = mock_dependencies();
+        let market_id = MarketId::unchecked("0x01edfab47f124748dc89998eb33144af734484ba07099014594321729a0ca16b");
+
+        let deps_ref = deps.as_ref();
+        let querier = InjectiveQuerier::new(&deps_ref.querier);
+        let mut market = querier.query_binary_options_market(&market_id).unwrap().market.unwrap();
+        assert_eq!(market.market_id, market_id);
+
+        market.status = MarketStatus::Expired;
+        deps.querier.binary_options_market_response_handler = create_binary_options_market_handler(Some(market.clone()));
+
+        let deps_ref = deps.as_ref();
+        let querier = InjectiveQuerier::new(&deps_ref.querier);
+        assert_eq!(querier.query_binary_options_market(&market_id).unwrap().market, Some(market));
+    }
+
+    #[test]
+    fn keyed_handlers_answer_per_key() {
+        let mut deps = mock_dependencies();
+        let subaccount_id = SubaccountId::unchecked("0xaf79152ac5df276d9a8e1e2e22822f9713474902000000000000000000000001");
+        let grant = Grant {
+            authorization: Authorization::Generic {
+                msg: "/injective.exchange.v1beta1.MsgCreateSpotLimitOrder".to_string(),
+            },
+            expiration: 0,
+        };
+        deps.querier.subaccount_trade_nonce_response_handler = create_subaccount_trade_nonces_handler(HashMap::from([(subaccount_id.clone(), 3)]));
+        deps.querier.grants_response_handler =
+            create_grants_by_pair_handler(HashMap::from([(("granter".to_string(), "grantee".to_string()), vec![grant.clone()])]));
+
+        let deps_ref = deps.as_ref();
+        let querier = InjectiveQuerier::new(&deps_ref.querier);
+        assert_eq!(querier.query_subaccount_trade_nonce(&subaccount_id).unwrap().nonce, 3);
+        let other_subaccount_id = SubaccountId::unchecked("0xaf79152ac5df276d9a8e1e2e22822f9713474902000000000000000000000002");
+        assert_eq!(querier.query_subaccount_trade_nonce(&other_subaccount_id).unwrap().nonce, 0);
+        assert_eq!(querier.query_grants("granter", "grantee", "", None).unwrap().grants, Some(vec![grant]));
+        assert_eq!(querier.query_grants("grantee", "granter", "", None).unwrap().grants, Some(vec![]));
+    }
+
+    #[test]
+    fn market_id_validate_returns_typed_errors() {
+        let mut deps = mock_dependencies();
+        let market_id = MarketId::unchecked("0x01edfab47f124748dc89998eb33144af734484ba07099014594321729a0ca16b");
+
+        let deps_ref = deps.as_ref();
+        let querier = InjectiveQuerier::new(&deps_ref.querier);
+        assert_eq!(market_id.clone().validate(&querier, MarketType::BinaryOptions), Ok(market_id.clone()));
+
+        deps.querier.binary_options_market_response_handler = create_binary_options_market_handler(None);
+
+        let deps_ref = deps.as_ref();
+        let querier = InjectiveQuerier::new(&deps_ref.querier);
+        assert_eq!(
+            market_id.clone().validate(&querier, MarketType::BinaryOptions),
+            Err(InjectiveError::MarketNotFound { market_id })
+        );
+    }
+}
@@ -0,0 +1,1108 @@
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::marker::PhantomData;
+use std::rc::Rc;
+
+use cosmwasm_std::testing::{MockApi, MockStorage};
+use cosmwasm_std::{to_json_binary, ContractResult, OwnedDeps, QuerierResult, StdError, StdResult, SystemResult};
+use injective_math::FPDecimal;
+use serde::Serialize;
+
+use crate::exchange::{
+    response::{QueryOrderbookResponse, SubaccountDepositsResponse, SubaccountPositionsResponse, SubaccountTradeNonceResponse},
+    types::{DenomDecimals, MarketVolume, Params, PriceLevel, VolumeByType},
+};
+use crate::exchange_mock_querier::{
+    HandlesAccountVolumeQuery, HandlesDenomDecimalQuery, HandlesDenomDecimalsQuery, HandlesDerivativePriceLevelsQuery, HandlesExchangeParamsQuery,
+    HandlesMarketAndSubaccountQuery, HandlesMarketIdQuery, HandlesMarketVolatilityQuery, HandlesMarketVolumeQuery, HandlesPriceLevelsQuery,
+    HandlesSubaccountAndDenomQuery, HandlesSubaccountIdQuery, HandlesTraderDerivativeOrdersToCancelUpToAmountQuery,
+    HandlesTraderSpotOrdersToCancelUpToAmountQuery, WasmMockQuerier,
+};
+use crate::oracle::volatility::TradeHistoryOptions;
+use crate::{
+    BinaryOptionsMarket, BinaryOptionsMarketResponse, CancellationStrategy, Deposit, DerivativeMarketResponse, DerivativePosition, EffectivePosition,
+    ExchangeParamsResponse, ExpiryFuturesMarketInfoResponse, FullDerivativeMarket, GenericTrimmedOrder, InjectiveQueryWrapper, MarketId,
+    MarketMidPriceAndTOBResponse, MarketVolatilityResponse, OrderSide, PerpetualMarketFundingResponse, PerpetualMarketInfoResponse, Position,
+    QueryAggregateMarketVolumeResponse, QueryAggregateVolumeResponse, QueryDenomDecimalResponse, QueryDenomDecimalsResponse,
+    QueryMarketAtomicExecutionFeeMultiplierResponse, SpotMarket, SpotMarketResponse, SubaccountDepositResponse,
+    SubaccountEffectivePositionInMarketResponse, SubaccountId, SubaccountPositionInMarketResponse, TraderDerivativeOrdersResponse,
+    TraderSpotOrdersResponse, TrimmedDerivativeLimitOrder, TrimmedSpotLimitOrder,
+};
+
+/// Exchange state shared between a test and the queriers answering from it, see [`WasmMockQuerier::attach_state`]
+pub type SharedMockState = Rc<RefCell<InjectiveMockState>>;
+
+/// In-memory exchange module for unit tests. Markets, deposits, positions, orders and orderbooks are stored per market,
+/// subaccount and denom, and anything missing is answered the way the chain does: no market, zero deposits, no position.
+#[derive(Clone, Debug, Default)]
+pub struct InjectiveMockState {
+    pub exchange_params: Option<Params>,
+    pub spot_markets: BTreeMap<MarketId, SpotMarket>,
+    pub derivative_markets: BTreeMap<MarketId, FullDerivativeMarket>,
+    pub binary_options_markets: BTreeMap<MarketId, BinaryOptionsMarket>,
+    pub deposits: BTreeMap<SubaccountId, BTreeMap<String, Deposit>>,
+    pub positions: BTreeMap<SubaccountId, BTreeMap<MarketId, Position>>,
+    pub spot_orders: BTreeMap<(MarketId, SubaccountId), Vec<TrimmedSpotLimitOrder>>,
+    pub derivative_orders: BTreeMap<(MarketId, SubaccountId), Vec<TrimmedDerivativeLimitOrder>>,
+    /// Orders created in the current block
+    pub transient_spot_orders: BTreeMap<(MarketId, SubaccountId), Vec<TrimmedSpotLimitOrder>>,
+    pub transient_derivative_orders: BTreeMap<(MarketId, SubaccountId), Vec<TrimmedDerivativeLimitOrder>>,
+    /// Price levels are expected best first, i.e. buys descending and sells ascending
+    pub spot_orderbooks: BTreeMap<MarketId, QueryOrderbookResponse>,
+    pub derivative_orderbooks: BTreeMap<MarketId, QueryOrderbookResponse>,
+    pub denom_decimals: BTreeMap<String, u64>,
+    pub trade_nonces: BTreeMap<SubaccountId, u32>,
+    /// Per market overrides, other markets use the multiplier of their market type from the exchange params
+    pub atomic_execution_fee_multipliers: BTreeMap<MarketId, FPDecimal>,
+    /// Volatility and trade history per market, the metadata and raw history are only returned when the query asks for them
+    pub market_volatilities: BTreeMap<MarketId, MarketVolatilityResponse>,
+    pub market_volumes: BTreeMap<MarketId, VolumeByType>,
+    /// Volumes per market of an account, keyed by the subaccount id or address passed to `query_aggregate_account_volume`
+    pub account_volumes: BTreeMap<String, BTreeMap<MarketId, VolumeByType>>,
+}
+
+impl InjectiveMockState {
+    pub fn into_shared(self) -> SharedMockState {
+        Rc::new(RefCell::new(self))
+    }
+
+    pub fn set_exchange_params(&mut self, params: Params) -> &mut Self {
+        self.exchange_params = Some(params);
+        self
+    }
+
+    pub fn add_spot_market(&mut self, market: SpotMarket) -> &mut Self {
+        self.spot_markets.insert(market.market_id.clone(), market);
+        self
+    }
+
+    pub fn add_derivative_market(&mut self, market: FullDerivativeMarket) -> &mut Self {
+        let market_id = market.market.as_ref().expect("full derivative market without a market").market_id.clone();
+        self.derivative_markets.insert(market_id, market);
+        self
+    }
+
+    pub fn add_binary_options_market(&mut self, market: BinaryOptionsMarket) -> &mut Self {
+        self.binary_options_markets.insert(market.market_id.clone(), market);
+        self
+    }
+
+    pub fn set_deposit(&mut self, subaccount_id: SubaccountId, denom: impl Into<String>, deposit: Deposit) -> &mut Self {
+        self.deposits.entry(subaccount_id).or_default().insert(denom.into(), deposit);
+        self
+    }
+
+    pub fn set_position(&mut self, market_id: MarketId, subaccount_id: SubaccountId, position: Position) -> &mut Self {
+        self.positions.entry(subaccount_id).or_default().insert(market_id, position);
+        self
+    }
+
+    pub fn remove_position(&mut self, market_id: &MarketId, subaccount_id: &SubaccountId) -> &mut Self {
+        if let Some(positions) = self.positions.get_mut(subaccount_id) {
+            positions.remove(market_id);
+        }
+        self
+    }
+
+    pub fn add_spot_order(&mut self, market_id: MarketId, subaccount_id: SubaccountId, order: TrimmedSpotLimitOrder) -> &mut Self {
+        self.spot_orders.entry((market_id, subaccount_id)).or_default().push(order);
+        self
+    }
+
+    pub fn add_derivative_order(&mut self, market_id: MarketId, subaccount_id: SubaccountId, order: TrimmedDerivativeLimitOrder) -> &mut Self {
+        self.derivative_orders.entry((market_id, subaccount_id)).or_default().push(order);
+        self
+    }
+
+    pub fn add_transient_spot_order(&mut self, market_id: MarketId, subaccount_id: SubaccountId, order: TrimmedSpotLimitOrder) -> &mut Self {
+        self.transient_spot_orders.entry((market_id, subaccount_id)).or_default().push(order);
+        self
+    }
+
+    pub fn add_transient_derivative_order(
+        &mut self,
+        market_id: MarketId,
+        subaccount_id: SubaccountId,
+        order: TrimmedDerivativeLimitOrder,
+    ) -> &mut Self {
+        self.transient_derivative_orders
+            .entry((market_id, subaccount_id))
+            .or_default()
+            .push(order);
+        self
+    }
+
+    /// Removes the spot and derivative orders of a subaccount in a market, transient ones included
+    pub fn clear_orders(&mut self, market_id: MarketId, subaccount_id: SubaccountId) -> &mut Self {
+        let key = (market_id, subaccount_id);
+        self.spot_orders.remove(&key);
+        self.derivative_orders.remove(&key);
+        self.transient_spot_orders.remove(&key);
+        self.transient_derivative_orders.remove(&key);
+        self
+    }
+
+    pub fn set_spot_orderbook(&mut self, market_id: MarketId, buys: Vec<PriceLevel>, sells: Vec<PriceLevel>) -> &mut Self {
+        self.spot_orderbooks.insert(
+            market_id,
+            QueryOrderbookResponse {
+                buys_price_level: buys,
+                sells_price_level: sells,
+            },
+        );
+        self
+    }
+
+    pub fn set_derivative_orderbook(&mut self, market_id: MarketId, buys: Vec<PriceLevel>, sells: Vec<PriceLevel>) -> &mut Self {
+        self.derivative_orderbooks.insert(
+            market_id,
+            QueryOrderbookResponse {
+                buys_price_level: buys,
+                sells_price_level: sells,
+            },
+        );
+        self
+    }
+
+    pub fn set_denom_decimals(&mut self, denom: impl Into<String>, decimals: u64) -> &mut Self {
+        self.denom_decimals.insert(denom.into(), decimals);
+        self
+    }
+
+    pub fn set_trade_nonce(&mut self, subaccount_id: SubaccountId, nonce: u32) -> &mut Self {
+        self.trade_nonces.insert(subaccount_id, nonce);
+        self
+    }
+
+    pub fn set_atomic_execution_fee_multiplier(&mut self, market_id: MarketId, multiplier: FPDecimal) -> &mut Self {
+        self.atomic_execution_fee_multipliers.insert(market_id, multiplier);
+        self
+    }
+
+    pub fn set_market_volatility(&mut self, market_id: MarketId, volatility: MarketVolatilityResponse) -> &mut Self {
+        self.market_volatilities.insert(market_id, volatility);
+        self
+    }
+
+    pub fn set_market_volume(&mut self, market_id: MarketId, volume: VolumeByType) -> &mut Self {
+        self.market_volumes.insert(market_id, volume);
+        self
+    }
+
+    pub fn set_account_volume(&mut self, account: impl Into<String>, market_id: MarketId, volume: VolumeByType) -> &mut Self {
+        self.account_volumes.entry(account.into()).or_default().insert(market_id, volume);
+        self
+    }
+
+    pub fn exchange_params_response(&self) -> ExchangeParamsResponse {
+        ExchangeParamsResponse {
+            params: self.exchange_params.clone(),
+        }
+    }
+
+    pub fn subaccount_deposit_response(&self, subaccount_id: SubaccountId, denom: String) -> SubaccountDepositResponse {
+        let deposit = self.deposits.get(&subaccount_id).and_then(|deposits| deposits.get(&denom));
+        SubaccountDepositResponse {
+            deposits: deposit.cloned().unwrap_or(Deposit {
+                available_balance: FPDecimal::ZERO,
+                total_balance: FPDecimal::ZERO,
+            }),
+        }
+    }
+
+    pub fn subaccount_deposits_response(&self, subaccount_id: SubaccountId) -> SubaccountDepositsResponse {
+        SubaccountDepositsResponse {
+            deposits: self.deposits.get(&subaccount_id).cloned().unwrap_or_default(),
+        }
+    }
+
+    pub fn subaccount_trade_nonce_response(&self, subaccount_id: SubaccountId) -> SubaccountTradeNonceResponse {
+        SubaccountTradeNonceResponse {
+            nonce: self.trade_nonces.get(&subaccount_id).copied().unwrap_or_default(),
+        }
+    }
+
+    pub fn spot_market_response(&self, market_id: MarketId) -> SpotMarketResponse {
+        SpotMarketResponse {
+            market: self.spot_markets.get(&market_id).cloned(),
+        }
+    }
+
+    pub fn derivative_market_response(&self, market_id: MarketId) -> DerivativeMarketResponse {
+        DerivativeMarketResponse {
+            market: self.derivative_markets.get(&market_id).cloned(),
+        }
+    }
+
+    pub fn binary_options_market_response(&self, market_id: MarketId) -> BinaryOptionsMarketResponse {
+        BinaryOptionsMarketResponse {
+            market: self.binary_options_markets.get(&market_id).cloned(),
+        }
+    }
+
+    pub fn perpetual_market_info_response(&self, market_id: MarketId) -> PerpetualMarketInfoResponse {
+        let perpetual_info = self.derivative_markets.get(&market_id).and_then(|market| market.perpetual_info());
+        PerpetualMarketInfoResponse {
+            info: perpetual_info.map(|state| state.market_info.to_owned()),
+        }
+    }
+
+    pub fn perpetual_market_funding_response(&self, market_id: MarketId) -> PerpetualMarketFundingResponse {
+        let perpetual_info = self.derivative_markets.get(&market_id).and_then(|market| market.perpetual_info());
+        PerpetualMarketFundingResponse {
+            state: perpetual_info.map(|state| state.funding_info.to_owned()),
+        }
+    }
+
+    pub fn expiry_futures_market_info_response(&self, market_id: MarketId) -> ExpiryFuturesMarketInfoResponse {
+        ExpiryFuturesMarketInfoResponse {
+            info: self
+                .derivative_markets
+                .get(&market_id)
+                .and_then(|market| market.expiry_futures_info())
+                .cloned(),
+        }
+    }
+
+    pub fn trader_spot_orders_response(&self, market_id: MarketId, subaccount_id: SubaccountId) -> TraderSpotOrdersResponse {
+        TraderSpotOrdersResponse {
+            orders: Some(self.spot_orders.get(&(market_id, subaccount_id)).cloned().unwrap_or_default()),
+        }
+    }
+
+    pub fn trader_derivative_orders_response(&self, market_id: MarketId, subaccount_id: SubaccountId) -> TraderDerivativeOrdersResponse {
+        TraderDerivativeOrdersResponse {
+            orders: Some(self.derivative_orders.get(&(market_id, subaccount_id)).cloned().unwrap_or_default()),
+        }
+    }
+
+    pub fn trader_transient_spot_orders_response(&self, market_id: MarketId, subaccount_id: SubaccountId) -> TraderSpotOrdersResponse {
+        TraderSpotOrdersResponse {
+            orders: Some(self.transient_spot_orders.get(&(market_id, subaccount_id)).cloned().unwrap_or_default()),
+        }
+    }
+
+    pub fn trader_transient_derivative_orders_response(&self, market_id: MarketId, subaccount_id: SubaccountId) -> TraderDerivativeOrdersResponse {
+        TraderDerivativeOrdersResponse {
+            orders: Some(
+                self.transient_derivative_orders
+                    .get(&(market_id, subaccount_id))
+                    .cloned()
+                    .unwrap_or_default(),
+            ),
+        }
+    }
+
+    /// Fails like the chain for unknown markets, and when the multiplier falls back to exchange params that are not set
+    pub fn market_atomic_execution_fee_multiplier_response(&self, market_id: MarketId) -> StdResult<QueryMarketAtomicExecutionFeeMultiplierResponse> {
+        if let Some(multiplier) = self.atomic_execution_fee_multipliers.get(&market_id) {
+            return Ok(QueryMarketAtomicExecutionFeeMultiplierResponse { multiplier: *multiplier });
+        }
+
+        let params = self
+            .exchange_params
+            .as_ref()
+            .ok_or_else(|| StdError::generic_err("exchange params are not set"))?;
+        let multiplier = if self.spot_markets.contains_key(&market_id) {
+            params.spot_atomic_market_order_fee_multiplier
+        } else if self.derivative_markets.contains_key(&market_id) {
+            params.derivative_atomic_market_order_fee_multiplier
+        } else if self.binary_options_markets.contains_key(&market_id) {
+            params.binary_options_atomic_market_order_fee_multiplier
+        } else {
+            return Err(StdError::generic_err(format!("market {} not found", market_id.as_str())));
+        };
+
+        Ok(QueryMarketAtomicExecutionFeeMultiplierResponse { multiplier })
+    }
+
+    /// Picks resting orders like the chain: in `strategy` order around `reference_price`, until the sell orders free
+    /// `base_amount` and the buy orders free `quote_amount`, maker fees included. Fails like the chain for unknown markets,
+    /// a sorting strategy without reference price or orders not freeing the full amounts.
+    pub fn trader_spot_orders_to_cancel_up_to_amount_response(
+        &self,
+        market_id: MarketId,
+        subaccount_id: SubaccountId,
+        base_amount: FPDecimal,
+        quote_amount: FPDecimal,
+        strategy: CancellationStrategy,
+        reference_price: Option<FPDecimal>,
+    ) -> StdResult<TraderSpotOrdersResponse> {
+        let market = self
+            .spot_markets
+            .get(&market_id)
+            .ok_or_else(|| StdError::generic_err(format!("market {} not found", market_id.as_str())))?;
+        let positive_maker_fee_rate = market.maker_fee_rate.max(FPDecimal::ZERO);
+
+        let mut orders = self.spot_orders.get(&(market_id, subaccount_id)).cloned().unwrap_or_default();
+        sort_for_cancellation(&mut orders, &strategy, reference_price)?;
+
+        let mut orders_to_cancel = Vec::new();
+        let (mut cumulative_base, mut cumulative_quote) = (FPDecimal::ZERO, FPDecimal::ZERO);
+        for order in orders {
+            let has_sufficient_base = cumulative_base >= base_amount;
+            let has_sufficient_quote = cumulative_quote >= quote_amount;
+            if has_sufficient_base && has_sufficient_quote {
+                break;
+            }
+            if (order.isBuy && has_sufficient_quote) || (!order.isBuy && has_sufficient_base) {
+                continue;
+            }
+
+            if order.isBuy {
+                let notional = order.fillable * order.price;
+                cumulative_quote += notional + notional * positive_maker_fee_rate;
+            } else {
+                cumulative_base += order.fillable;
+            }
+            orders_to_cancel.push(order);
+        }
+
+        if cumulative_base < base_amount || cumulative_quote < quote_amount {
+            return Err(StdError::generic_err("orders do not cover the amount to cancel"));
+        }
+        Ok(TraderSpotOrdersResponse {
+            orders: Some(orders_to_cancel),
+        })
+    }
+
+    /// Like [`Self::trader_spot_orders_to_cancel_up_to_amount_response`], every order freeing its margin plus maker fees in quote
+    pub fn trader_derivative_orders_to_cancel_up_to_amount_response(
+        &self,
+        market_id: MarketId,
+        subaccount_id: SubaccountId,
+        quote_amount: FPDecimal,
+        strategy: CancellationStrategy,
+        reference_price: Option<FPDecimal>,
+    ) -> StdResult<TraderDerivativeOrdersResponse> {
+        let maker_fee_rate = match self.derivative_markets.get(&market_id).and_then(|market| market.market.as_ref()) {
+            Some(market) => market.maker_fee_rate,
+            None => self
+                .binary_options_markets
+                .get(&market_id)
+                .map(|market| market.maker_fee_rate)
+                .ok_or_else(|| StdError::generic_err(format!("market {} not found", market_id.as_str())))?,
+        };
+        let positive_maker_fee_rate = maker_fee_rate.max(FPDecimal::ZERO);
+
+        let mut orders = self.derivative_orders.get(&(market_id, subaccount_id)).cloned().unwrap_or_default();
+        sort_for_cancellation(&mut orders, &strategy, reference_price)?;
+
+        let mut orders_to_cancel = Vec::new();
+        let mut cumulative_quote = FPDecimal::ZERO;
+        for order in orders {
+            if cumulative_quote >= quote_amount {
+                break;
+            }
+
+            cumulative_quote += order.margin + order.fillable * order.price * positive_maker_fee_rate;
+            orders_to_cancel.push(order);
+        }
+
+        if cumulative_quote < quote_amount {
+            return Err(StdError::generic_err("orders do not cover the amount to cancel"));
+        }
+        Ok(TraderDerivativeOrdersResponse {
+            orders: Some(orders_to_cancel),
+        })
+    }
+
+    pub fn subaccount_positions_response(&self, subaccount_id: SubaccountId) -> SubaccountPositionsResponse {
+        let positions = self.positions.get(&subaccount_id).into_iter().flatten();
+        SubaccountPositionsResponse {
+            state: positions
+                .map(|(market_id, position)| DerivativePosition {
+                    subaccount_id: subaccount_id.clone(),
+                    market_id: market_id.clone(),
+                    position: position.clone(),
+                })
+                .collect(),
+        }
+    }
+
+    pub fn subaccount_position_in_market_response(&self, market_id: MarketId, subaccount_id: SubaccountId) -> SubaccountPositionInMarketResponse {
+        SubaccountPositionInMarketResponse {
+            state: self.position(&market_id, &subaccount_id).cloned(),
+        }
+    }
+
+    /// Values the position at the market's mark price, including pending funding of perpetual markets
+    pub fn subaccount_effective_position_in_market_response(
+        &self,
+        market_id: MarketId,
+        subaccount_id: SubaccountId,
+    ) -> SubaccountEffectivePositionInMarketResponse {
+        let market = self.derivative_markets.get(&market_id);
+        let state = self.position(&market_id, &subaccount_id).map(|position| {
+            let mark_price = market.map_or(position.entry_price, |market| market.mark_price);
+            let funding = market.and_then(|market| market.perpetual_info()).map(|state| &state.funding_info);
+
+            EffectivePosition {
+                is_long: position.isLong,
+                quantity: position.quantity,
+                entry_price: position.entry_price,
+                effective_margin: position.margin + position.get_unrealized_pnl(mark_price, funding),
+            }
+        });
+
+        SubaccountEffectivePositionInMarketResponse { state }
+    }
+
+    pub fn spot_market_mid_price_and_tob_response(&self, market_id: MarketId) -> MarketMidPriceAndTOBResponse {
+        mid_price_and_tob(self.spot_orderbooks.get(&market_id))
+    }
+
+    pub fn derivative_market_mid_price_and_tob_response(&self, market_id: MarketId) -> MarketMidPriceAndTOBResponse {
+        mid_price_and_tob(self.derivative_orderbooks.get(&market_id))
+    }
+
+    pub fn spot_orderbook_response(&self, market_id: MarketId, order_side: OrderSide) -> QueryOrderbookResponse {
+        let orderbook = self.spot_orderbooks.get(&market_id).cloned().unwrap_or(QueryOrderbookResponse {
+            buys_price_level: vec![],
+            sells_price_level: vec![],
+        });
+
+        match order_side {
+            OrderSide::Buy => QueryOrderbookResponse {
+                sells_price_level: vec![],
+                ..orderbook
+            },
+            OrderSide::Sell => QueryOrderbookResponse {
+                buys_price_level: vec![],
+                ..orderbook
+            },
+            OrderSide::Unspecified => orderbook,
+        }
+    }
+
+    pub fn derivative_orderbook_response(&self, market_id: MarketId) -> QueryOrderbookResponse {
+        self.derivative_orderbooks.get(&market_id).cloned().unwrap_or(QueryOrderbookResponse {
+            buys_price_level: vec![],
+            sells_price_level: vec![],
+        })
+    }
+
+    /// Unknown denoms have zero decimals, like on chain
+    pub fn denom_decimal_response(&self, denom: String) -> QueryDenomDecimalResponse {
+        QueryDenomDecimalResponse {
+            decimals: self.denom_decimals.get(&denom).copied().unwrap_or_default(),
+        }
+    }
+
+    pub fn denom_decimals_response(&self, denoms: Vec<String>) -> QueryDenomDecimalsResponse {
+        QueryDenomDecimalsResponse {
+            denom_decimals: denoms
+                .into_iter()
+                .map(|denom| DenomDecimals {
+                    decimals: self.denom_decimals.get(&denom).copied().unwrap_or_default(),
+                    denom,
+                })
+                .collect(),
+        }
+    }
+
+    /// Markets without trades have no volatility, like on chain
+    pub fn market_volatility_response(&self, market_id: MarketId, trade_history_options: TradeHistoryOptions) -> MarketVolatilityResponse {
+        match self.market_volatilities.get(&market_id) {
+            Some(volatility) => MarketVolatilityResponse {
+                volatility: volatility.volatility,
+                history_metadata: volatility.history_metadata.clone().filter(|_| trade_history_options.include_metadata),
+                raw_history: volatility.raw_history.clone().filter(|_| trade_history_options.include_raw_history),
+            },
+            None => MarketVolatilityResponse {
+                volatility: None,
+                history_metadata: None,
+                raw_history: None,
+            },
+        }
+    }
+
+    /// Markets without trades have a zero volume
+    pub fn aggregate_market_volume_response(&self, market_id: MarketId) -> QueryAggregateMarketVolumeResponse {
+        QueryAggregateMarketVolumeResponse {
+            volume: self.market_volumes.get(&market_id).cloned().unwrap_or(VolumeByType {
+                maker_volume: FPDecimal::ZERO,
+                taker_volume: FPDecimal::ZERO,
+            }),
+        }
+    }
+
+    pub fn aggregate_account_volume_response(&self, account: String) -> QueryAggregateVolumeResponse {
+        let volumes = self.account_volumes.get(&account).into_iter().flatten();
+        QueryAggregateVolumeResponse {
+            aggregate_volumes: Some(
+                volumes
+                    .map(|(market_id, volume)| MarketVolume {
+                        market_id: market_id.clone(),
+                        volume: volume.clone(),
+                    })
+                    .collect(),
+            ),
+        }
+    }
+
+    fn position(&self, market_id: &MarketId, subaccount_id: &SubaccountId) -> Option<&Position> {
+        self.positions.get(subaccount_id).and_then(|positions| positions.get(market_id))
+    }
+}
+
+fn mid_price_and_tob(orderbook: Option<&QueryOrderbookResponse>) -> MarketMidPriceAndTOBResponse {
+    let best_buy_price = orderbook.and_then(|orderbook| orderbook.buys_price_level.first()).map(|level| level.p);
+    let best_sell_price = orderbook.and_then(|orderbook| orderbook.sells_price_level.first()).map(|level| level.p);
+    let mid_price = match (best_buy_price, best_sell_price) {
+        (Some(buy), Some(sell)) => Some((buy + sell) / FPDecimal::TWO),
+        _ => None,
+    };
+
+    MarketMidPriceAndTOBResponse {
+        mid_price,
+        best_buy_price,
+        best_sell_price,
+    }
+}
+
+impl WasmMockQuerier {
+    /// Answers every exchange query from `state`, replacing their handlers. Handlers assigned afterwards still take
+    /// precedence, queries of other modules keep their handlers or defaults.
+    pub fn attach_state(&mut self, state: &SharedMockState) {
+        self.exchange_params_response_handler = Some(state_handler(state, InjectiveMockState::exchange_params_response));
+        self.subaccount_deposit_response_handler = Some(state_handler(state, InjectiveMockState::subaccount_deposit_response));
+        self.subaccount_deposits_response_handler = Some(state_handler(state, InjectiveMockState::subaccount_deposits_response));
+        self.subaccount_trade_nonce_response_handler = Some(state_handler(state, InjectiveMockState::subaccount_trade_nonce_response));
+        self.spot_market_response_handler = Some(state_handler(state, InjectiveMockState::spot_market_response));
+        self.derivative_market_response_handler = Some(state_handler(state, InjectiveMockState::derivative_market_response));
+        self.binary_options_market_response_handler = Some(state_handler(state, InjectiveMockState::binary_options_market_response));
+        self.perpetual_market_info_response_handler = Some(state_handler(state, InjectiveMockState::perpetual_market_info_response));
+        self.perpetual_market_funding_response_handler = Some(state_handler(state, InjectiveMockState::perpetual_market_funding_response));
+        self.expiry_futures_market_info_response_handler = Some(state_handler(state, InjectiveMockState::expiry_futures_market_info_response));
+        self.trader_spot_orders_response_handler = Some(state_handler(state, InjectiveMockState::trader_spot_orders_response));
+        self.trader_derivative_orders_response_handler = Some(state_handler(state, InjectiveMockState::trader_derivative_orders_response));
+        self.trader_transient_spot_orders_response_handler = Some(state_handler(state, InjectiveMockState::trader_transient_spot_orders_response));
+        self.trader_transient_derivative_orders_response_handler =
+            Some(state_handler(state, InjectiveMockState::trader_transient_derivative_orders_response));
+        self.subaccount_positions_response_handler = Some(state_handler(state, InjectiveMockState::subaccount_positions_response));
+        self.subaccount_position_in_market_response_handler = Some(state_handler(state, InjectiveMockState::subaccount_position_in_market_response));
+        self.subaccount_effective_position_in_market_response_handler =
+            Some(state_handler(state, InjectiveMockState::subaccount_effective_position_in_market_response));
+        self.spot_market_mid_price_and_tob_response_handler = Some(state_handler(state, InjectiveMockState::spot_market_mid_price_and_tob_response));
+        self.derivative_market_mid_price_and_tob_response_handler =
+            Some(state_handler(state, InjectiveMockState::derivative_market_mid_price_and_tob_response));
+        self.spot_market_orderbook_response_handler = Some(state_handler(state, InjectiveMockState::spot_orderbook_response));
+        self.derivative_market_orderbook_response_handler = Some(state_handler(state, InjectiveMockState::derivative_orderbook_response));
+        self.denom_decimal_handler = Some(state_handler(state, InjectiveMockState::denom_decimal_response));
+        self.denom_decimals_handler = Some(state_handler(state, InjectiveMockState::denom_decimals_response));
+        self.market_atomic_execution_fee_multiplier_response_handler = Some(Box::new(FallibleStateHandler {
+            state: state.clone(),
+            respond: InjectiveMockState::market_atomic_execution_fee_multiplier_response,
+        }));
+
+        self.trader_spot_orders_to_cancel_up_to_amount_response_handler = Some(Box::new(FallibleStateHandler {
+            state: state.clone(),
+            respond: InjectiveMockState::trader_spot_orders_to_cancel_up_to_amount_response,
+        }));
+        self.trader_derivative_orders_to_cancel_up_to_amount_response_handler = Some(Box::new(FallibleStateHandler {
+            state: state.clone(),
+            respond: InjectiveMockState::trader_derivative_orders_to_cancel_up_to_amount_response,
+        }));
+        self.market_volatility_response_handler = Some(state_handler(state, InjectiveMockState::market_volatility_response));
+        self.aggregate_market_volume_handler = Some(state_handler(state, InjectiveMockState::aggregate_market_volume_response));
+        self.aggregate_account_volume_handler = Some(state_handler(state, InjectiveMockState::aggregate_account_volume_response));
+    }
+}
+
+pub fn mock_dependencies_with_state(state: &SharedMockState) -> OwnedDeps<MockStorage, MockApi, WasmMockQuerier, InjectiveQueryWrapper> {
+    let mut custom_querier = WasmMockQuerier::new();
+    custom_querier.attach_state(state);
+
+    OwnedDeps {
+        api: MockApi::default(),
+        storage: MockStorage::default(),
+        querier: custom_querier,
+        custom_query_type: PhantomData,
+    }
+}
+
+/// Handler reading its response from the shared state at query time, so changes made between queries are visible
+struct StateHandler<F> {
+    state: SharedMockState,
+    respond: F,
+}
+
+fn state_handler<F>(state: &SharedMockState, respond: F) -> Box<StateHandler<F>> {
+    Box::new(StateHandler {
+        state: state.clone(),
+        respond,
+    })
+}
+
+fn to_querier_result<R: Serialize>(response: &R) -> QuerierResult {
+    SystemResult::Ok(ContractResult::from(to_json_binary(response)))
+}
+
+/// Like [`StateHandler`] for queries the chain can fail, the error is returned as a contract error
+struct FallibleStateHandler<F> {
+    state: SharedMockState,
+    respond: F,
+}
+
+impl<F, R> HandlesExchangeParamsQuery for StateHandler<F>
+where
+    F: Fn(&InjectiveMockState) -> R,
+    R: Serialize,
+{
+    fn handle(&self) -> QuerierResult {
+        to_querier_result(&(self.respond)(&self.state.borrow()))
+    }
+}
+
+impl<F, R> HandlesMarketIdQuery for StateHandler<F>
+where
+    F: Fn(&InjectiveMockState, MarketId) -> R,
+    R: Serialize,
+{
+    fn handle(&self, market_id: MarketId) -> QuerierResult {
+        to_querier_result(&(self.respond)(&self.state.borrow(), market_id))
+    }
+}
+
+impl<F, R> HandlesDerivativePriceLevelsQuery for StateHandler<F>
+where
+    F: Fn(&InjectiveMockState, MarketId) -> R,
+    R: Serialize,
+{
+    fn handle(&self, market_id: MarketId) -> QuerierResult {
+        to_querier_result(&(self.respond)(&self.state.borrow(), market_id))
+    }
+}
+
+impl<F, R> HandlesSubaccountIdQuery for StateHandler<F>
+where
+    F: Fn(&InjectiveMockState, SubaccountId) -> R,
+    R: Serialize,
+{
+    fn handle(&self, subaccount_id: SubaccountId) -> QuerierResult {
+        to_querier_result(&(self.respond)(&self.state.borrow(), subaccount_id))
+    }
+}
+
+impl<F, R> HandlesMarketAndSubaccountQuery for StateHandler<F>
+where
+    F: Fn(&InjectiveMockState, MarketId, SubaccountId) -> R,
+    R: Serialize,
+{
+    fn handle(&self, market_id: MarketId, subaccount_id: SubaccountId) -> QuerierResult {
+        to_querier_result(&(self.respond)(&self.state.borrow(), market_id, subaccount_id))
+    }
+}
+
+impl<F, R> HandlesSubaccountAndDenomQuery for StateHandler<F>
+where
+    F: Fn(&InjectiveMockState, SubaccountId, String) -> R,
+    R: Serialize,
+{
+    fn handle(&self, subaccount_id: SubaccountId, denom: String) -> QuerierResult {
+        to_querier_result(&(self.respond)(&self.state.borrow(), subaccount_id, denom))
+    }
+}
+
+impl<F, R> HandlesPriceLevelsQuery for StateHandler<F>
+where
+    F: Fn(&InjectiveMockState, MarketId, OrderSide) -> R,
+    R: Serialize,
+{
+    fn handle(&self, market_id: MarketId, order_side: OrderSide) -> QuerierResult {
+        to_querier_result(&(self.respond)(&self.state.borrow(), market_id, order_side))
+    }
+}
+
+impl<F, R> HandlesDenomDecimalQuery for StateHandler<F>
+where
+    F: Fn(&InjectiveMockState, String) -> R,
+    R: Serialize,
+{
+    fn handle(&self, denom: String) -> QuerierResult {
+        to_querier_result(&(self.respond)(&self.state.borrow(), denom))
+    }
+}
+
+impl<F, R> HandlesDenomDecimalsQuery for StateHandler<F>
+where
+    F: Fn(&InjectiveMockState, Vec<String>) -> R,
+    R: Serialize,
+{
+    fn handle(&self, denoms: Vec<String>) -> QuerierResult {
+        to_querier_result(&(self.respond)(&self.state.borrow(), denoms))
+    }
+}
+
+impl<F, R> HandlesMarketVolatilityQuery for StateHandler<F>
+where
+    F: Fn(&InjectiveMockState, MarketId, TradeHistoryOptions) -> R,
+    R: Serialize,
+{
+    fn handle(&self, market_id: MarketId, trade_history_options: TradeHistoryOptions) -> QuerierResult {
+        to_querier_result(&(self.respond)(&self.state.borrow(), market_id, trade_history_options))
+    }
+}
+
+impl<F, R> HandlesMarketVolumeQuery for StateHandler<F>
+where
+    F: Fn(&InjectiveMockState, MarketId) -> R,
+    R: Serialize,
+{
+    fn handle(&self, market_id: MarketId) -> QuerierResult {
+        to_querier_result(&(self.respond)(&self.state.borrow(), market_id))
+    }
+}
+
+impl<F, R> HandlesAccountVolumeQuery for StateHandler<F>
+where
+    F: Fn(&InjectiveMockState, String) -> R,
+    R: Serialize,
+{
+    fn handle(&self, account: String) -> QuerierResult {
+        to_querier_result(&(self.respond)(&self.state.borrow(), account))
+    }
+}
+
+impl<F, R> HandlesMarketIdQuery for FallibleStateHandler<F>
+where
+    F: Fn(&InjectiveMockState, MarketId) -> StdResult<R>,
+    R: Serialize,
+{
+    fn handle(&self, market_id: MarketId) -> QuerierResult {
+        let response = (self.respond)(&self.state.borrow(), market_id);
+        SystemResult::Ok(ContractResult::from(response.and_then(|response| to_json_binary(&response))))
+    }
+}
+
+impl<F, R> HandlesTraderSpotOrdersToCancelUpToAmountQuery for FallibleStateHandler<F>
+where
+    F: Fn(&InjectiveMockState, MarketId, SubaccountId, FPDecimal, FPDecimal, CancellationStrategy, Option<FPDecimal>) -> StdResult<R>,
+    R: Serialize,
+{
+    fn handle(
+        &self,
+        market_id: MarketId,
+        subaccount_id: SubaccountId,
+        base_amount: FPDecimal,
+        quote_amount: FPDecimal,
+        strategy: CancellationStrategy,
+        reference_price: Option<FPDecimal>,
+    ) -> QuerierResult {
+        let response = (self.respond)(
+            &self.state.borrow(),
+            market_id,
+            subaccount_id,
+            base_amount,
+            quote_amount,
+            strategy,
+            reference_price,
+        );
+        SystemResult::Ok(ContractResult::from(response.and_then(|response| to_json_binary(&response))))
+    }
+}
+
+impl<F, R> HandlesTraderDerivativeOrdersToCancelUpToAmountQuery for FallibleStateHandler<F>
+where
+    F: Fn(&InjectiveMockState, MarketId, SubaccountId, FPDecimal, CancellationStrategy, Option<FPDecimal>) -> StdResult<R>,
+    R: Serialize,
+{
+    fn handle(
+        &self,
+        market_id: MarketId,
+        subaccount_id: SubaccountId,
+        quote_amount: FPDecimal,
+        strategy: CancellationStrategy,
+        reference_price: Option<FPDecimal>,
+    ) -> QuerierResult {
+        let response = (self.respond)(&self.state.borrow(), market_id, subaccount_id, quote_amount, strategy, reference_price);
+        SystemResult::Ok(ContractResult::from(response.and_then(|response| to_json_binary(&response))))
+    }
+}
+
+// the chain sorts by distance to the reference price, orders furthest from being filled being the worst
+fn sort_for_cancellation<T: GenericTrimmedOrder>(
+    orders: &mut [T],
+    strategy: &CancellationStrategy,
+    reference_price: Option<FPDecimal>,
+) -> StdResult<()> {
+    let worst_first = match strategy {
+        CancellationStrategy::UnspecifiedOrder => return Ok(()),
+        CancellationStrategy::FromWorstToBest => true,
+        CancellationStrategy::FromBestToWorst => false,
+    };
+    let reference_price = reference_price.ok_or_else(|| StdError::generic_err("reference price is required by the cancellation strategy"))?;
+    let distance = |order: &T| {
+        if order.is_buy() {
+            reference_price - order.get_price()
+        } else {
+            order.get_price() - reference_price
+        }
+    };
+
+    orders.sort_by(|first, second| {
+        let ordering = distance(first).cmp(&distance(second));
+        if worst_first {
+            ordering.reverse()
+        } else {
+            ordering
+        }
+    });
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use injective_math::FPDecimal;
+
+    use crate::exchange::types::{MarketVolume, VolumeByType};
+    use crate::exchange_mock_state::{mock_dependencies_with_state, InjectiveMockState};
+    use crate::querier::InjectiveQuerier;
+    use crate::test_helpers::testing_helpers::{create_mock_spot_market, inj_mock_deps};
+    use crate::{
+        CancellationStrategy, Deposit, MarketId, MarketVolatilityResponse, MetadataStatistics, OrderSide, PriceLevel, SubaccountId,
+        TrimmedSpotLimitOrder,
+    };
+
+    const FIRST_SUBACCOUNT: &str = "0xaf79152ac5df276d9a8e1e2e22822f9713474902000000000000000000000000";
+    const SECOND_SUBACCOUNT: &str = "0xaf79152ac5df276d9a8e1e2e22822f9713474902000000000000000000000001";
+
+    fn deposit(amount: u128) -> Deposit {
+        Deposit {
+            available_balance: FPDecimal::from(amount),
+            total_balance: FPDecimal::from(amount),
+        }
+    }
+
+    #[test]
+    fn deposits_are_kept_per_subaccount_and_follow_state_changes() {
+        let first = SubaccountId::unchecked(FIRST_SUBACCOUNT);
+        let second = SubaccountId::unchecked(SECOND_SUBACCOUNT);
+
+        let mut state = InjectiveMockState::default();
+        state
+            .set_deposit(first.clone(), "usdt", deposit(100))
+            .set_deposit(second.clone(), "usdt", deposit(5));
+        let state = state.into_shared();
+
+        let deps = mock_dependencies_with_state(&state);
+        let deps = deps.as_ref();
+        let querier = InjectiveQuerier::new(&deps.querier);
+
+        assert_eq!(querier.query_subaccount_deposit(&first, &"usdt").unwrap().deposits, deposit(100));
+        assert_eq!(querier.query_subaccount_deposit(&second, &"usdt").unwrap().deposits, deposit(5));
+        assert_eq!(querier.query_subaccount_deposit(&second, &"inj").unwrap().deposits, deposit(0));
+
+        state.borrow_mut().set_deposit(second.clone(), "inj", deposit(7));
+        assert_eq!(querier.query_subaccount_deposits(&second).unwrap().deposits.len(), 2);
+        assert_eq!(querier.query_subaccount_deposit(&second, &"inj").unwrap().deposits, deposit(7));
+    }
+
+    #[test]
+    fn markets_and_orderbooks_are_answered_per_market() {
+        let inj_market = create_mock_spot_market("inj", 0);
+        let atom_market = create_mock_spot_market("atom", 1);
+        let missing_market = MarketId::unchecked("0x0000000000000000000000000000000000000000000000000000000000000000");
+
+        let mut state = InjectiveMockState::default();
+        state
+            .add_spot_market(inj_market.clone())
+            .add_spot_market(atom_market.clone())
+            .set_spot_orderbook(
+                atom_market.market_id.clone(),
+                vec![PriceLevel::new(9u128.into(), 1u128.into())],
+                vec![PriceLevel::new(11u128.into(), 2u128.into())],
+            );
+        let deps = mock_dependencies_with_state(&state.into_shared());
+        let deps = deps.as_ref();
+        let querier = InjectiveQuerier::new(&deps.querier);
+
+        assert_eq!(querier.query_spot_market(&inj_market.market_id).unwrap().market, Some(inj_market.clone()));
+        assert_eq!(
+            querier.query_spot_market(&atom_market.market_id).unwrap().market,
+            Some(atom_market.clone())
+        );
+        assert_eq!(querier.query_spot_market(&missing_market).unwrap().market, None);
+
+        let tob = querier.query_spot_market_mid_price_and_tob(&atom_market.market_id).unwrap();
+        assert_eq!(tob.mid_price, Some(FPDecimal::from(10u128)));
+        assert_eq!(
+            querier.query_spot_market_mid_price_and_tob(&inj_market.market_id).unwrap().mid_price,
+            None
+        );
+
+        let buys = querier
+            .query_spot_market_orderbook(&atom_market.market_id, OrderSide::Buy, None, None)
+            .unwrap();
+        assert_eq!(buys.buys_price_level.len(), 1);
+        assert!(buys.sells_price_level.is_empty());
+    }
+
+    #[test]
+    fn exchange_queries_outside_the_state_do_not_fall_back_to_defaults() {
+        let spot_market = create_mock_spot_market("inj", 0);
+        let overridden_market = create_mock_spot_market("atom", 1);
+        let subaccount_id = SubaccountId::unchecked(FIRST_SUBACCOUNT);
+
+        let state = InjectiveMockState::default().into_shared();
+        state
+            .borrow_mut()
+            .add_spot_market(spot_market.clone())
+            .set_atomic_execution_fee_multiplier(overridden_market.market_id.clone(), FPDecimal::from(3u128));
+        let deps = mock_dependencies_with_state(&state);
+        let deps = deps.as_ref();
+        let querier = InjectiveQuerier::new(&deps.querier);
+
+        assert_eq!(querier.query_exchange_params().unwrap().params, None);
+        assert!(querier
+            .query_trader_transient_spot_orders(&spot_market.market_id, &subaccount_id)
+            .unwrap()
+            .orders
+            .unwrap()
+            .is_empty());
+        assert_eq!(
+            querier
+                .query_market_atomic_execution_fee_multiplier(&overridden_market.market_id)
+                .unwrap()
+                .multiplier,
+            FPDecimal::from(3u128)
+        );
+        assert!(querier.query_market_atomic_execution_fee_multiplier(&spot_market.market_id).is_err());
+
+        let default_deps = inj_mock_deps(|_| {});
+        let default_deps = default_deps.as_ref();
+        let params = InjectiveQuerier::new(&default_deps.querier)
+            .query_exchange_params()
+            .unwrap()
+            .params
+            .unwrap();
+        let spot_multiplier = params.spot_atomic_market_order_fee_multiplier;
+        state.borrow_mut().set_exchange_params(params);
+        assert_eq!(
+            querier
+                .query_market_atomic_execution_fee_multiplier(&spot_market.market_id)
+                .unwrap()
+                .multiplier,
+            spot_multiplier
+        );
+    }
+
+    #[test]
+    fn volatility_and_volumes_are_answered_per_market_and_account() {
+        let market_id = MarketId::unchecked("0x78c2d3af98c517b164070a739681d4bd4d293101e7ffc3a30968945329b47ec6");
+        let other_market_id = MarketId::unchecked("0x0000000000000000000000000000000000000000000000000000000000000000");
+        let volume = |maker: u128, taker: u128| VolumeByType {
+            maker_volume: FPDecimal::from(maker),
+            taker_volume: FPDecimal::from(taker),
+        };
+        let metadata = MetadataStatistics {
+            group_count: 1,
+            records_sample_size: 1,
+            mean: FPDecimal::from(10u128),
+            twap: FPDecimal::from(10u128),
+            first_timestamp: 1,
+            last_timestamp: 1,
+            min_price: FPDecimal::from(10u128),
+            max_price: FPDecimal::from(10u128),
+            median_price: FPDecimal::from(10u128),
+        };
+
+        let mut state = InjectiveMockState::default();
+        state
+            .set_market_volatility(
+                market_id.clone(),
+                MarketVolatilityResponse {
+                    volatility: Some(FPDecimal::from(2u128)),
+                    history_metadata: Some(metadata.clone()),
+                    raw_history: Some(vec![]),
+                },
+            )
+            .set_market_volume(market_id.clone(), volume(5, 7))
+            .set_account_volume(FIRST_SUBACCOUNT, market_id.clone(), volume(1, 2));
+        let deps = mock_dependencies_with_state(&state.into_shared());
+        let deps = deps.as_ref();
+        let querier = InjectiveQuerier::new(&deps.querier);
+
+        let volatility = querier.query_market_volatility(&market_id, 60, 3600, false, true).unwrap();
+        assert_eq!(volatility.volatility, Some(FPDecimal::from(2u128)));
+        assert_eq!(volatility.history_metadata, Some(metadata));
+        assert_eq!(volatility.raw_history, None);
+        assert_eq!(
+            querier.query_market_volatility(&other_market_id, 60, 3600, true, true).unwrap(),
+            MarketVolatilityResponse {
+                volatility: None,
+                history_metadata: None,
+                raw_history: None,
+            }
+        );
+
+        assert_eq!(querier.query_aggregate_market_volume(&market_id).unwrap().volume, volume(5, 7));
+        assert_eq!(querier.query_aggregate_market_volume(&other_market_id).unwrap().volume, volume(0, 0));
+        assert_eq!(
+            querier.query_aggregate_account_volume(&FIRST_SUBACCOUNT).unwrap().aggregate_volumes,
+            Some(vec![MarketVolume {
+                market_id,
+                volume: volume(1, 2),
+            }])
+        );
+        assert_eq!(
+            querier.query_aggregate_account_volume(&SECOND_SUBACCOUNT).unwrap().aggregate_volumes,
+            Some(vec![])
+        );
+    }
+
+    #[test]
+    fn orders_to_cancel_up_to_amount_are_picked_from_resting_orders() {
+        let market = create_mock_spot_market("inj", 0);
+        let subaccount_id = SubaccountId::unchecked(FIRST_SUBACCOUNT);
+        let order = |price: u128, fillable: u128, is_buy: bool| TrimmedSpotLimitOrder {
+            price: FPDecimal::from(price),
+            quantity: FPDecimal::from(fillable),
+            fillable: FPDecimal::from(fillable),
+            isBuy: is_buy,
+            order_hash: format!("0x{price}"),
+        };
+
+        let mut state = InjectiveMockState::default();
+        state
+            .add_spot_market(market.clone())
+            .add_spot_order(market.market_id.clone(), subaccount_id.clone(), order(9, 1, true))
+            .add_spot_order(market.market_id.clone(), subaccount_id.clone(), order(11, 2, false))
+            .add_spot_order(market.market_id.clone(), subaccount_id.clone(), order(8, 1, true));
+        let deps = mock_dependencies_with_state(&state.into_shared());
+        let deps = deps.as_ref();
+        let querier = InjectiveQuerier::new(&deps.querier);
+        let hashes_to_cancel = |base: u128, quote: u128, strategy: CancellationStrategy| {
+            querier
+                .query_spot_orders_to_cancel_up_to_amount(
+                    &market.market_id,
+                    &subaccount_id,
+                    FPDecimal::from(base),
+                    FPDecimal::from(quote),
+                    strategy,
+                    Some(FPDecimal::from(10u128)),
+                )
+                .map(|response| response.orders.unwrap().into_iter().map(|order| order.order_hash).collect::<Vec<_>>())
+        };
+
+        // the 9 buy is skipped once the 8 buy covers the quote amount
+        assert_eq!(
+            hashes_to_cancel(1, 5, CancellationStrategy::FromWorstToBest).unwrap(),
+            vec!["0x8", "0x11"]
+        );
+        assert_eq!(hashes_to_cancel(0, 5, CancellationStrategy::FromBestToWorst).unwrap(), vec!["0x9"]);
+        assert_eq!(hashes_to_cancel(0, 5, CancellationStrategy::UnspecifiedOrder).unwrap(), vec!["0x9"]);
+        assert!(hashes_to_cancel(0, 100, CancellationStrategy::UnspecifiedOrder).is_err());
+        assert!(querier
+            .query_spot_orders_to_cancel_up_to_amount(
+                &market.market_id,
+                &subaccount_id,
+                FPDecimal::ZERO,
+                FPDecimal::ONE,
+                CancellationStrategy::FromWorstToBest,
+                None,
+            )
+            .is_err());
+    }
+}
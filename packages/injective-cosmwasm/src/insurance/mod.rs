@@ -0,0 +1,2 @@
+pub mod response;
+pub mod types;
@@ -0,0 +1,101 @@
+use injective_math::FPDecimal;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::exchange::types::MarketId;
+use crate::oracle::types::OracleType;
+
+/// Shares minted for the first deposit into an empty insurance fund, as in the insurance module
+pub const INSURANCE_FUND_INITIAL_SUPPLY: u128 = 1_000_000_000_000_000_000;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct InsuranceFund {
+    pub deposit_denom: String,
+    /// Denom of the share token minted to underwriters
+    pub insurance_pool_token_denom: String,
+    /// Nanoseconds between a redemption request and the time it can be claimed
+    #[serde(default)]
+    pub redemption_notice_period_duration: Option<i64>,
+    pub balance: FPDecimal,
+    pub total_share: FPDecimal,
+    pub market_id: MarketId,
+    pub market_ticker: String,
+    pub oracle_base: String,
+    pub oracle_quote: String,
+    pub oracle_type: OracleType,
+    /// Expiry of the market, -1 for perpetual markets
+    pub expiry: i64,
+}
+
+impl InsuranceFund {
+    pub fn share_denom(&self) -> &str {
+        &self.insurance_pool_token_denom
+    }
+
+    /// Deposit amount paid out for redeeming `shares`, truncated like the insurance module does
+    pub fn redeemable_amount(&self, shares: FPDecimal) -> FPDecimal {
+        if self.total_share.is_zero() {
+            return FPDecimal::ZERO;
+        }
+        (self.balance * shares / self.total_share).int()
+    }
+
+    /// Shares minted for underwriting `deposit`
+    pub fn shares_for_deposit(&self, deposit: FPDecimal) -> FPDecimal {
+        if self.total_share.is_zero() || self.balance.is_zero() {
+            return FPDecimal::from(INSURANCE_FUND_INITIAL_SUPPLY);
+        }
+        (self.total_share * deposit / self.balance).int()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use injective_math::FPDecimal;
+
+    use crate::exchange::types::MarketId;
+    use crate::insurance::types::{InsuranceFund, INSURANCE_FUND_INITIAL_SUPPLY};
+    use crate::oracle::types::OracleType;
+
+    fn fund(balance: &str, total_share: &str) -> InsuranceFund {
+        InsuranceFund {
+            deposit_denom: "peggy0xdAC17F958D2ee523a2206206994597C13D831ec7".to_string(),
+            insurance_pool_token_denom: "share1".to_string(),
+            redemption_notice_period_duration: Some(1_209_600_000_000_000),
+            balance: FPDecimal::must_from_str(balance),
+            total_share: FPDecimal::must_from_str(total_share),
+            market_id: MarketId::unchecked("0x17ef48032cb24375ba7c2e39f384e56433bcab20cbee9a7357e4cba2eb00abe6"),
+            market_ticker: "INJ/USDT PERP".to_string(),
+            oracle_base: "0x2d9315a88f3019f8efa88dfe9c0f0843712da0bac814461e27733f6b83eb51b3".to_string(),
+            oracle_quote: "0x1fc18861232290221461220bd4e2acd1dcdfbc89c84092c93c18bdc7756c1588".to_string(),
+            oracle_type: OracleType::Pyth,
+            expiry: -1,
+        }
+    }
+
+    #[test]
+    fn redeemable_amount_is_pro_rata_and_truncated() {
+        let fund = fund("1000000", "3000000");
+
+        assert_eq!(
+            fund.redeemable_amount(FPDecimal::must_from_str("1000000")),
+            FPDecimal::must_from_str("333333")
+        );
+        assert_eq!(
+            fund.redeemable_amount(FPDecimal::must_from_str("3000000")),
+            FPDecimal::must_from_str("1000000")
+        );
+        assert_eq!(fund.shares_for_deposit(FPDecimal::must_from_str("500")), FPDecimal::must_from_str("1500"));
+    }
+
+    #[test]
+    fn empty_fund_mints_initial_supply() {
+        let fund = fund("0", "0");
+
+        assert_eq!(fund.redeemable_amount(FPDecimal::ONE), FPDecimal::ZERO);
+        assert_eq!(
+            fund.shares_for_deposit(FPDecimal::must_from_str("100")),
+            FPDecimal::from(INSURANCE_FUND_INITIAL_SUPPLY)
+        );
+    }
+}
@@ -1,34 +1,64 @@
+pub use auction::{
+    response::{AuctionParamsResponse, CurrentAuctionBasketResponse},
+    types::AuctionParams,
+    valuation::{BasketPriceSource, BasketValuation, ValuedCoin},
+};
+pub use authz::{
+    response::{Grant, GrantAuthorization, GranteeGrantsResponse, GranterGrantsResponse, GrantsResponse, PageResponse},
+    types::{Authorization, BatchUpdateOrdersAuthz, MarketsAuthz, PageRequest},
+};
+pub use error::InjectiveError;
 pub use exchange::{
+    account_snapshot::{AccountSnapshot, AccountSnapshotBuilder, MarketOrders},
+    binary_options_market::BinaryOptionsMarket,
     cancel::CancellationStrategy,
+    cid::{CidGenerator, MAX_CID_LENGTH},
     derivative::{
         DerivativeLimitOrder, DerivativeMarketOrder, DerivativeOrder, DerivativePosition, EffectivePosition, Position, TrimmedDerivativeLimitOrder,
     },
     derivative_market::{
-        DerivativeMarket, FullDerivativeMarket, FullDerivativeMarketPerpetualInfo, PerpetualMarketFunding, PerpetualMarketInfo, PerpetualMarketState,
+        DerivativeMarket, ExpiryFuturesMarketInfo, FullDerivativeMarket, FullDerivativeMarketInfo, FullDerivativeMarketPerpetualInfo,
+        PerpetualMarketFunding, PerpetualMarketInfo, PerpetualMarketState,
     },
-    market::MarketStatus,
+    fee::{FeeCalculator, Liquidity, TradeFee},
+    market::{GenericMarket, MarketStatus},
     order::{GenericOrder, GenericTrimmedOrder, OrderData, OrderInfo, OrderSide, OrderType},
+    order_builder::{OrderBuilder, RoundingDirection},
+    order_hash::{compute_derivative_order_hash, compute_spot_order_hash, OrderHashGenerator},
+    orderbook::{FillTarget, OrderbookFill, OrderbookSimulation},
     response::{
-        DerivativeMarketResponse, ExchangeParamsResponse, MarketMidPriceAndTOBResponse, MarketVolatilityResponse, OracleVolatilityResponse,
-        PerpetualMarketFundingResponse, PerpetualMarketInfoResponse, QueryAggregateMarketVolumeResponse, QueryAggregateVolumeResponse,
-        QueryDenomDecimalResponse, QueryDenomDecimalsResponse, QueryMarketAtomicExecutionFeeMultiplierResponse, SpotMarketResponse,
-        SubaccountDepositResponse, SubaccountEffectivePositionInMarketResponse, SubaccountPositionInMarketResponse, TraderDerivativeOrdersResponse,
-        TraderSpotOrdersResponse,
+        BinaryOptionsMarketResponse, DerivativeMarketResponse, ExchangeParamsResponse, ExpiryFuturesMarketInfoResponse, MarketMidPriceAndTOBResponse,
+        MarketVolatilityResponse, OracleVolatilityResponse, PerpetualMarketFundingResponse, PerpetualMarketInfoResponse,
+        QueryAggregateMarketVolumeResponse, QueryAggregateVolumeResponse, QueryDenomDecimalResponse, QueryDenomDecimalsResponse,
+        QueryMarketAtomicExecutionFeeMultiplierResponse, SpotMarketResponse, SubaccountDepositResponse, SubaccountDepositsResponse,
+        SubaccountEffectivePositionInMarketResponse, SubaccountPositionInMarketResponse, SubaccountPositionsResponse, SubaccountTradeNonceResponse,
+        TraderDerivativeOrdersResponse, TraderSpotOrdersResponse,
     },
-    spot::{MsgCreateSpotMarketOrderResponse, SpotLimitOrder, SpotMarketOrder, SpotOrder, TrimmedSpotLimitOrder},
-    spot_market::SpotMarket,
+    spot::{MsgCreateSpotMarketOrderResponse, SpotLimitOrder, SpotMarketOrder, SpotMarketOrderResults, SpotOrder, TrimmedSpotLimitOrder},
+    spot_market::{SpotMarket, SpotMarketScale},
     subaccount::{
         addr_to_bech32, bech32_to_hex, checked_address_to_subaccount_id, get_default_subaccount_id_for_checked_address, is_default_subaccount,
-        subaccount_id_to_ethereum_address, subaccount_id_to_injective_address, subaccount_id_to_unchecked_injective_address,
+        subaccount_id_to_ethereum_address, subaccount_id_to_injective_address, subaccount_id_to_unchecked_injective_address, try_addr_to_bech32,
+        try_bech32_to_hex, try_checked_address_to_subaccount_id, try_get_default_subaccount_id_for_checked_address,
+        try_subaccount_id_to_injective_address,
     },
     types::{DenomDecimals, Deposit, Hash, MarketId, MarketType, Params, PriceLevel, ShortSubaccountId, SubaccountId},
 };
+pub use insurance::{
+    response::InsuranceFundResponse,
+    types::{InsuranceFund, INSURANCE_FUND_INITIAL_SUPPLY},
+};
 pub use oracle::{
+    pyth::PythPrice,
     response::{OraclePriceResponse, PythPriceResponse},
     types::{OracleInfo, OracleType, PriceAttestation, PricePairState, PythStatus},
     volatility::{MetadataStatistics, PriceRecord, TradeHistoryOptions, TradeRecord},
 };
-pub use wasmx::types::FundingMode;
+pub use tokenfactory::types::{DenomAuthorityMetadata, TokenFactoryDenom};
+pub use wasmx::{
+    msg::WasmxSudoMsg,
+    types::{ContractRegistrationRequest, FundingMode},
+};
 
 #[cfg(not(target_arch = "wasm32"))]
 pub use exchange_mock_querier::handlers::*;
@@ -36,16 +66,30 @@ pub use exchange_mock_querier::handlers::*;
 #[cfg(not(target_arch = "wasm32"))]
 pub use exchange_mock_querier::*;
 
+#[cfg(not(target_arch = "wasm32"))]
+pub use exchange_mock_keyed::KeyedHandler;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use exchange_mock_state::{mock_dependencies_with_state, InjectiveMockState, SharedMockState};
+
 pub use msg::{
-    cancel_derivative_order_msg, cancel_spot_order_msg, create_activate_contract_msg, create_batch_update_orders_msg, create_burn_tokens_msg,
-    create_deactivate_contract_msg, create_deposit_msg, create_derivative_market_order_msg, create_external_transfer_msg,
-    create_increase_position_margin_msg, create_liquidate_position_msg, create_mint_tokens_msg, create_new_denom_msg,
-    create_privileged_execute_contract_msg, create_relay_pyth_prices_msg, create_rewards_opt_out_msg, create_set_token_metadata_msg,
-    create_spot_market_order_msg, create_subaccount_transfer_msg, create_update_contract_msg, create_withdraw_msg, InjectiveMsg, InjectiveMsgWrapper,
+    cancel_binary_options_order_by_cid_msg, cancel_binary_options_order_msg, cancel_derivative_order_by_cid_msg, cancel_derivative_order_msg,
+    cancel_spot_order_by_cid_msg, cancel_spot_order_msg, create_activate_contract_msg, create_auction_bid_msg,
+    create_batch_derivative_limit_orders_msg, create_batch_spot_limit_orders_msg, create_batch_update_orders_msg,
+    create_binary_options_market_order_msg, create_burn_tokens_msg, create_change_admin_msg, create_deactivate_contract_msg, create_deposit_msg,
+    create_derivative_limit_order_msg, create_derivative_market_order_msg, create_external_transfer_msg, create_increase_position_margin_msg,
+    create_liquidate_position_msg, create_mint_tokens_msg, create_new_denom_msg, create_privileged_execute_contract_msg,
+    create_register_contract_msg, create_relay_pyth_prices_msg, create_request_redemption_msg, create_rewards_opt_out_msg,
+    create_set_token_metadata_msg, create_spot_limit_order_msg, create_spot_market_order_msg, create_subaccount_transfer_msg, create_underwrite_msg,
+    create_update_contract_msg, create_withdraw_msg, InjectiveMsg, InjectiveMsgWrapper,
 };
 
 pub use querier::InjectiveQuerier;
 pub use query::{InjectiveQuery, InjectiveQueryWrapper};
+pub use reply::{
+    reply_data, BatchCreateLimitOrdersReply, BatchUpdateOrdersReply, DerivativeMarketOrderReply, DerivativeMarketOrderResults, ExchangeReply,
+    LimitOrderReply, PositionDelta,
+};
 pub use route::InjectiveRoute;
 #[cfg(not(target_arch = "wasm32"))]
 pub use test_helpers::testing_helpers::{
@@ -53,18 +97,26 @@ pub use test_helpers::testing_helpers::{
     TEST_MARKET_ID_3, TEST_MARKET_ID_4, TEST_MARKET_ID_5, TEST_MARKET_ID_6, TEST_MARKET_ID_7, TEST_MARKET_ID_8, TEST_MARKET_ID_9,
 };
 
+pub mod auction;
 pub mod authz;
+pub mod error;
 pub mod exchange;
+pub mod insurance;
 pub mod msg;
 pub mod oracle;
 pub mod querier;
 pub mod query;
+pub mod reply;
 pub mod route;
 pub mod tokenfactory;
 pub mod wasmx;
 
+#[cfg(not(target_arch = "wasm32"))]
+mod exchange_mock_keyed;
 #[cfg(not(target_arch = "wasm32"))]
 mod exchange_mock_querier;
+#[cfg(not(target_arch = "wasm32"))]
+mod exchange_mock_state;
 mod test_helpers;
 
 // This export is added to all contracts that import this package, signifying that they require
@@ -1,16 +1,18 @@
-use cosmwasm_std::{Addr, BankMsg, Coin, CosmosMsg, CustomMsg, Deps, StdError, StdResult};
+use cosmwasm_std::{Addr, BankMsg, Coin, CosmosMsg, CustomMsg, Deps, StdResult};
 use injective_math::FPDecimal;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::error::InjectiveError;
 use crate::exchange::{
     derivative::{derivative_order_to_short, ShortDerivativeOrder},
     order::{order_data_to_short, OrderData, ShortOrderData},
     privileged_action::coins_to_string,
     spot::{spot_order_to_short, ShortSpotOrder, SpotOrder},
-    subaccount::{is_default_subaccount, subaccount_id_to_injective_address},
+    subaccount::{is_default_subaccount, subaccount_id_to_injective_address, try_subaccount_id_to_injective_address},
     types::{MarketId, SubaccountId},
 };
+use crate::wasmx::types::ContractRegistrationRequest;
 use crate::InjectiveQueryWrapper;
 use crate::{exchange::derivative::DerivativeOrder, oracle::types::PriceAttestation, route::InjectiveRoute};
 
@@ -63,11 +65,33 @@ pub enum InjectiveMsg {
         sender: Addr,
         order: DerivativeOrder,
     },
+    CreateBinaryOptionsMarketOrder {
+        sender: Addr,
+        order: DerivativeOrder,
+    },
+    CreateSpotLimitOrder {
+        sender: Addr,
+        order: SpotOrder,
+    },
+    CreateDerivativeLimitOrder {
+        sender: Addr,
+        order: DerivativeOrder,
+    },
+    BatchCreateSpotLimitOrders {
+        sender: Addr,
+        orders: Vec<SpotOrder>,
+    },
+    BatchCreateDerivativeLimitOrders {
+        sender: Addr,
+        orders: Vec<DerivativeOrder>,
+    },
     CancelDerivativeOrder {
         sender: Addr,
         market_id: MarketId,
         subaccount_id: SubaccountId,
         order_hash: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        cid: Option<String>,
         order_mask: i32,
     },
     CancelSpotOrder {
@@ -75,6 +99,17 @@ pub enum InjectiveMsg {
         market_id: MarketId,
         subaccount_id: SubaccountId,
         order_hash: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        cid: Option<String>,
+    },
+    CancelBinaryOptionsOrder {
+        sender: Addr,
+        market_id: MarketId,
+        subaccount_id: SubaccountId,
+        order_hash: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        cid: Option<String>,
+        order_mask: i32,
     },
     IncreasePositionMargin {
         sender: Addr,
@@ -130,6 +165,12 @@ pub enum InjectiveMsg {
         sender: Addr,
         amount: Coin,
     },
+    /// Transfers the admin rights of a factory denom, only callable by its current admin
+    ChangeAdmin {
+        sender: Addr,
+        denom: String,
+        new_admin_address: String,
+    },
     /// Sets metadata of token-factory token
     SetTokenMetadata {
         denom: String,
@@ -153,6 +194,25 @@ pub enum InjectiveMsg {
         sender: Addr,
         contract_address: Addr,
     },
+    RegisterContract {
+        sender: Addr,
+        contract_registration_request: ContractRegistrationRequest,
+    },
+    Bid {
+        sender: Addr,
+        bid_amount: Coin,
+        round: u64,
+    },
+    Underwrite {
+        sender: Addr,
+        market_id: MarketId,
+        deposit: Coin,
+    },
+    RequestRedemption {
+        sender: Addr,
+        market_id: MarketId,
+        amount: Coin,
+    },
 }
 
 pub fn create_deposit_msg(sender: Addr, subaccount_id: SubaccountId, amount: Coin) -> CosmosMsg<InjectiveMsgWrapper> {
@@ -190,7 +250,12 @@ pub fn create_subaccount_transfer_msg(
 
     let is_external_transfer = sender != to_address;
     if is_external_transfer {
-        return create_external_transfer_msg(deps, source_subaccount_id, destination_subaccount_id, amount);
+        return Ok(create_external_transfer_msg(
+            deps,
+            source_subaccount_id,
+            destination_subaccount_id,
+            amount,
+        )?);
     }
 
     if is_default_subaccount(destination_subaccount_id) {
@@ -214,11 +279,11 @@ pub fn create_external_transfer_msg(
     source_subaccount_id: &SubaccountId,
     destination_subaccount_id: &SubaccountId,
     amount: &Coin,
-) -> StdResult<Vec<CosmosMsg<InjectiveMsgWrapper>>> {
-    let sender = subaccount_id_to_injective_address(source_subaccount_id, deps)?;
+) -> Result<Vec<CosmosMsg<InjectiveMsgWrapper>>, InjectiveError> {
+    let sender = try_subaccount_id_to_injective_address(source_subaccount_id, deps)?;
 
     if is_default_subaccount(destination_subaccount_id) {
-        let to_address = subaccount_id_to_injective_address(destination_subaccount_id, deps)?;
+        let to_address = try_subaccount_id_to_injective_address(destination_subaccount_id, deps)?;
 
         let bank_send_msg = CosmosMsg::Bank(BankMsg::Send {
             to_address: to_address.to_string(),
@@ -234,7 +299,7 @@ pub fn create_external_transfer_msg(
     }
 
     if is_default_subaccount(source_subaccount_id) {
-        return Err(StdError::generic_err("Cannot send from default subaccount to external subaccount"));
+        return Err(InjectiveError::ExternalTransferFromDefaultSubaccount);
     }
 
     Ok(vec![InjectiveMsgWrapper {
@@ -265,6 +330,46 @@ pub fn create_derivative_market_order_msg(sender: Addr, order: DerivativeOrder)
     .into()
 }
 
+pub fn create_binary_options_market_order_msg(sender: Addr, order: DerivativeOrder) -> CosmosMsg<InjectiveMsgWrapper> {
+    InjectiveMsgWrapper {
+        route: InjectiveRoute::Exchange,
+        msg_data: InjectiveMsg::CreateBinaryOptionsMarketOrder { sender, order },
+    }
+    .into()
+}
+
+pub fn create_spot_limit_order_msg(sender: Addr, order: SpotOrder) -> CosmosMsg<InjectiveMsgWrapper> {
+    InjectiveMsgWrapper {
+        route: InjectiveRoute::Exchange,
+        msg_data: InjectiveMsg::CreateSpotLimitOrder { sender, order },
+    }
+    .into()
+}
+
+pub fn create_derivative_limit_order_msg(sender: Addr, order: DerivativeOrder) -> CosmosMsg<InjectiveMsgWrapper> {
+    InjectiveMsgWrapper {
+        route: InjectiveRoute::Exchange,
+        msg_data: InjectiveMsg::CreateDerivativeLimitOrder { sender, order },
+    }
+    .into()
+}
+
+pub fn create_batch_spot_limit_orders_msg(sender: Addr, orders: Vec<SpotOrder>) -> CosmosMsg<InjectiveMsgWrapper> {
+    InjectiveMsgWrapper {
+        route: InjectiveRoute::Exchange,
+        msg_data: InjectiveMsg::BatchCreateSpotLimitOrders { sender, orders },
+    }
+    .into()
+}
+
+pub fn create_batch_derivative_limit_orders_msg(sender: Addr, orders: Vec<DerivativeOrder>) -> CosmosMsg<InjectiveMsgWrapper> {
+    InjectiveMsgWrapper {
+        route: InjectiveRoute::Exchange,
+        msg_data: InjectiveMsg::BatchCreateDerivativeLimitOrders { sender, orders },
+    }
+    .into()
+}
+
 pub fn cancel_spot_order_msg(sender: Addr, market_id: MarketId, subaccount_id: SubaccountId, order_hash: String) -> CosmosMsg<InjectiveMsgWrapper> {
     InjectiveMsgWrapper {
         route: InjectiveRoute::Exchange,
@@ -273,6 +378,7 @@ pub fn cancel_spot_order_msg(sender: Addr, market_id: MarketId, subaccount_id: S
             market_id,
             subaccount_id,
             order_hash,
+            cid: None,
         },
     }
     .into()
@@ -292,6 +398,87 @@ pub fn cancel_derivative_order_msg(
             market_id,
             subaccount_id,
             order_hash,
+            cid: None,
+            order_mask,
+        },
+    }
+    .into()
+}
+
+pub fn cancel_binary_options_order_msg(
+    sender: Addr,
+    market_id: MarketId,
+    subaccount_id: SubaccountId,
+    order_hash: String,
+    order_mask: i32,
+) -> CosmosMsg<InjectiveMsgWrapper> {
+    InjectiveMsgWrapper {
+        route: InjectiveRoute::Exchange,
+        msg_data: InjectiveMsg::CancelBinaryOptionsOrder {
+            sender,
+            market_id,
+            subaccount_id,
+            order_hash,
+            cid: None,
+            order_mask,
+        },
+    }
+    .into()
+}
+
+/// Cancels a spot order by the client order id it was created with
+pub fn cancel_spot_order_by_cid_msg(sender: Addr, market_id: MarketId, subaccount_id: SubaccountId, cid: String) -> CosmosMsg<InjectiveMsgWrapper> {
+    InjectiveMsgWrapper {
+        route: InjectiveRoute::Exchange,
+        msg_data: InjectiveMsg::CancelSpotOrder {
+            sender,
+            market_id,
+            subaccount_id,
+            order_hash: "".to_string(),
+            cid: Some(cid),
+        },
+    }
+    .into()
+}
+
+/// Cancels a derivative order by the client order id it was created with
+pub fn cancel_derivative_order_by_cid_msg(
+    sender: Addr,
+    market_id: MarketId,
+    subaccount_id: SubaccountId,
+    cid: String,
+    order_mask: i32,
+) -> CosmosMsg<InjectiveMsgWrapper> {
+    InjectiveMsgWrapper {
+        route: InjectiveRoute::Exchange,
+        msg_data: InjectiveMsg::CancelDerivativeOrder {
+            sender,
+            market_id,
+            subaccount_id,
+            order_hash: "".to_string(),
+            cid: Some(cid),
+            order_mask,
+        },
+    }
+    .into()
+}
+
+/// Cancels a binary options order by the client order id it was created with
+pub fn cancel_binary_options_order_by_cid_msg(
+    sender: Addr,
+    market_id: MarketId,
+    subaccount_id: SubaccountId,
+    cid: String,
+    order_mask: i32,
+) -> CosmosMsg<InjectiveMsgWrapper> {
+    InjectiveMsgWrapper {
+        route: InjectiveRoute::Exchange,
+        msg_data: InjectiveMsg::CancelBinaryOptionsOrder {
+            sender,
+            market_id,
+            subaccount_id,
+            order_hash: "".to_string(),
+            cid: Some(cid),
             order_mask,
         },
     }
@@ -420,6 +607,18 @@ pub fn create_new_denom_msg(sender: String, subdenom: String) -> CosmosMsg<Injec
     .into()
 }
 
+pub fn create_change_admin_msg(sender: Addr, denom: String, new_admin_address: String) -> CosmosMsg<InjectiveMsgWrapper> {
+    InjectiveMsgWrapper {
+        route: InjectiveRoute::Tokenfactory,
+        msg_data: InjectiveMsg::ChangeAdmin {
+            sender,
+            denom,
+            new_admin_address,
+        },
+    }
+    .into()
+}
+
 pub fn create_set_token_metadata_msg(denom: String, name: String, symbol: String, decimals: u8) -> CosmosMsg<InjectiveMsgWrapper> {
     InjectiveMsgWrapper {
         route: InjectiveRoute::Tokenfactory,
@@ -472,3 +671,251 @@ pub fn create_deactivate_contract_msg(sender: Addr, contract_address: Addr) -> C
     }
     .into()
 }
+
+/// Registers a contract to receive `WasmxSudoMsg::BeginBlocker` every block, the request is validated before the message is built
+pub fn create_register_contract_msg(
+    sender: Addr,
+    contract_registration_request: ContractRegistrationRequest,
+) -> Result<CosmosMsg<InjectiveMsgWrapper>, InjectiveError> {
+    contract_registration_request.validate()?;
+
+    Ok(InjectiveMsgWrapper {
+        route: InjectiveRoute::Wasmx,
+        msg_data: InjectiveMsg::RegisterContract {
+            sender,
+            contract_registration_request,
+        },
+    }
+    .into())
+}
+
+pub fn create_underwrite_msg(sender: Addr, market_id: MarketId, deposit: Coin) -> CosmosMsg<InjectiveMsgWrapper> {
+    InjectiveMsgWrapper {
+        route: InjectiveRoute::Insurance,
+        msg_data: InjectiveMsg::Underwrite { sender, market_id, deposit },
+    }
+    .into()
+}
+
+/// Starts the redemption of `amount` insurance fund shares, the deposit is paid out after the fund's notice period
+pub fn create_request_redemption_msg(sender: Addr, market_id: MarketId, amount: Coin) -> CosmosMsg<InjectiveMsgWrapper> {
+    InjectiveMsgWrapper {
+        route: InjectiveRoute::Insurance,
+        msg_data: InjectiveMsg::RequestRedemption { sender, market_id, amount },
+    }
+    .into()
+}
+
+/// Bids `bid_amount` INJ in the burn auction, `round` must be the current round or the bid is rejected
+pub fn create_auction_bid_msg(sender: Addr, bid_amount: Coin, round: u64) -> CosmosMsg<InjectiveMsgWrapper> {
+    InjectiveMsgWrapper {
+        route: InjectiveRoute::Auction,
+        msg_data: InjectiveMsg::Bid { sender, bid_amount, round },
+    }
+    .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{to_json_string, Addr, CosmosMsg};
+    use injective_math::FPDecimal;
+
+    use crate::exchange::spot::SpotOrder;
+    use crate::exchange::{
+        derivative::DerivativeOrder,
+        order::{OrderData, OrderType},
+        types::{MarketId, SubaccountId},
+    };
+    use crate::msg::{
+        cancel_binary_options_order_by_cid_msg, cancel_binary_options_order_msg, cancel_derivative_order_by_cid_msg, cancel_derivative_order_msg,
+        cancel_spot_order_by_cid_msg, cancel_spot_order_msg, create_batch_derivative_limit_orders_msg, create_batch_spot_limit_orders_msg,
+        create_batch_update_orders_msg, create_binary_options_market_order_msg, create_derivative_limit_order_msg, create_register_contract_msg,
+        create_spot_limit_order_msg, InjectiveMsgWrapper,
+    };
+    use crate::wasmx::types::{ContractRegistrationRequest, FundingMode};
+    use crate::InjectiveError;
+
+    const SENDER: &str = "inj1cml96vmptgw99syqrrz8az79xer2pcgp0a885r";
+    const MARKET_ID: &str = "0x01edfab47f124748dc89998eb33144af734484ba07099014594321729a0ca16b";
+    const SUBACCOUNT_ID: &str = "0xc7dca7c15c364865f77a4fb67ab11dc95502e6fe000000000000000000000001";
+
+    fn custom_json(msg: CosmosMsg<InjectiveMsgWrapper>) -> String {
+        match msg {
+            CosmosMsg::Custom(wrapper) => to_json_string(&wrapper).unwrap(),
+            msg => panic!("expected a custom message, got {msg:?}"),
+        }
+    }
+
+    fn derivative_order(order_type: OrderType) -> DerivativeOrder {
+        DerivativeOrder::new(
+            FPDecimal::must_from_str("0.55"),
+            FPDecimal::must_from_str("10"),
+            FPDecimal::must_from_str("5.5"),
+            order_type,
+            MarketId::unchecked(MARKET_ID),
+            SubaccountId::unchecked(SUBACCOUNT_ID),
+            None,
+            None,
+        )
+    }
+
+    #[test]
+    fn binary_options_msgs_use_exchange_route() {
+        let order = custom_json(create_binary_options_market_order_msg(
+            Addr::unchecked(SENDER),
+            derivative_order(OrderType::Buy),
+        ));
+        let cancel = custom_json(cancel_binary_options_order_msg(
+            Addr::unchecked(SENDER),
+            MarketId::unchecked(MARKET_ID),
+            SubaccountId::unchecked(SUBACCOUNT_ID),
+            "0xabc".to_string(),
+            1,
+        ));
+
+        assert_eq!(
+            order,
+            r#"{"route":"exchange","msg_data":{"create_binary_options_market_order":{"sender":"inj1cml96vmptgw99syqrrz8az79xer2pcgp0a885r","order":{"market_id":"0x01edfab47f124748dc89998eb33144af734484ba07099014594321729a0ca16b","order_info":{"subaccount_id":"0xc7dca7c15c364865f77a4fb67ab11dc95502e6fe000000000000000000000001","fee_recipient":null,"price":"0.55","quantity":"10","cid":null},"order_type":1,"margin":"5.5","trigger_price":null}}}}"#
+        );
+        assert_eq!(
+            cancel,
+            r#"{"route":"exchange","msg_data":{"cancel_binary_options_order":{"sender":"inj1cml96vmptgw99syqrrz8az79xer2pcgp0a885r","market_id":"0x01edfab47f124748dc89998eb33144af734484ba07099014594321729a0ca16b","subaccount_id":"0xc7dca7c15c364865f77a4fb67ab11dc95502e6fe000000000000000000000001","order_hash":"0xabc","order_mask":1}}}"#
+        );
+    }
+
+    fn spot_order(order_type: OrderType) -> SpotOrder {
+        SpotOrder::new(
+            FPDecimal::must_from_str("12.5"),
+            FPDecimal::must_from_str("2"),
+            order_type,
+            &MarketId::unchecked(MARKET_ID),
+            SubaccountId::unchecked(SUBACCOUNT_ID),
+            Some(Addr::unchecked(SENDER)),
+            Some("cid-1".to_string()),
+        )
+    }
+
+    #[test]
+    fn limit_order_msgs_use_chain_variants() {
+        let sender = Addr::unchecked(SENDER);
+
+        let spot_json = r#"{"market_id":"0x01edfab47f124748dc89998eb33144af734484ba07099014594321729a0ca16b","order_info":{"subaccount_id":"0xc7dca7c15c364865f77a4fb67ab11dc95502e6fe000000000000000000000001","fee_recipient":"inj1cml96vmptgw99syqrrz8az79xer2pcgp0a885r","price":"12.5","quantity":"2","cid":"cid-1"},"order_type":7,"trigger_price":null}"#;
+        let derivative_json = r#"{"market_id":"0x01edfab47f124748dc89998eb33144af734484ba07099014594321729a0ca16b","order_info":{"subaccount_id":"0xc7dca7c15c364865f77a4fb67ab11dc95502e6fe000000000000000000000001","fee_recipient":null,"price":"0.55","quantity":"10","cid":null},"order_type":2,"margin":"5.5","trigger_price":null}"#;
+
+        assert_eq!(
+            custom_json(create_spot_limit_order_msg(sender.clone(), spot_order(OrderType::BuyPo))),
+            format!(r#"{{"route":"exchange","msg_data":{{"create_spot_limit_order":{{"sender":"{SENDER}","order":{spot_json}}}}}}}"#)
+        );
+        assert_eq!(
+            custom_json(create_derivative_limit_order_msg(sender.clone(), derivative_order(OrderType::Sell))),
+            format!(r#"{{"route":"exchange","msg_data":{{"create_derivative_limit_order":{{"sender":"{SENDER}","order":{derivative_json}}}}}}}"#)
+        );
+        assert_eq!(
+            custom_json(create_batch_spot_limit_orders_msg(
+                sender.clone(),
+                vec![spot_order(OrderType::BuyPo), spot_order(OrderType::BuyPo)]
+            )),
+            format!(
+                r#"{{"route":"exchange","msg_data":{{"batch_create_spot_limit_orders":{{"sender":"{SENDER}","orders":[{spot_json},{spot_json}]}}}}}}"#
+            )
+        );
+        assert_eq!(
+            custom_json(create_batch_derivative_limit_orders_msg(sender, vec![derivative_order(OrderType::Sell)])),
+            format!(
+                r#"{{"route":"exchange","msg_data":{{"batch_create_derivative_limit_orders":{{"sender":"{SENDER}","orders":[{derivative_json}]}}}}}}"#
+            )
+        );
+    }
+
+    #[test]
+    fn cancel_msgs_only_send_cid_when_cancelling_by_cid() {
+        let sender = Addr::unchecked(SENDER);
+        let market_id = MarketId::unchecked(MARKET_ID);
+        let subaccount_id = SubaccountId::unchecked(SUBACCOUNT_ID);
+        let ids = format!(r#""sender":"{SENDER}","market_id":"{MARKET_ID}","subaccount_id":"{SUBACCOUNT_ID}""#);
+
+        assert_eq!(
+            custom_json(cancel_spot_order_msg(
+                sender.clone(),
+                market_id.clone(),
+                subaccount_id.clone(),
+                "0xabc".to_string()
+            )),
+            format!(r#"{{"route":"exchange","msg_data":{{"cancel_spot_order":{{{ids},"order_hash":"0xabc"}}}}}}"#)
+        );
+        assert_eq!(
+            custom_json(cancel_spot_order_by_cid_msg(
+                sender.clone(),
+                market_id.clone(),
+                subaccount_id.clone(),
+                "cid-1".to_string()
+            )),
+            format!(r#"{{"route":"exchange","msg_data":{{"cancel_spot_order":{{{ids},"order_hash":"","cid":"cid-1"}}}}}}"#)
+        );
+        assert_eq!(
+            custom_json(cancel_derivative_order_msg(
+                sender.clone(),
+                market_id.clone(),
+                subaccount_id.clone(),
+                "0xabc".to_string(),
+                1
+            )),
+            format!(r#"{{"route":"exchange","msg_data":{{"cancel_derivative_order":{{{ids},"order_hash":"0xabc","order_mask":1}}}}}}"#)
+        );
+        assert_eq!(
+            custom_json(cancel_derivative_order_by_cid_msg(
+                sender.clone(),
+                market_id.clone(),
+                subaccount_id.clone(),
+                "cid-1".to_string(),
+                1
+            )),
+            format!(r#"{{"route":"exchange","msg_data":{{"cancel_derivative_order":{{{ids},"order_hash":"","cid":"cid-1","order_mask":1}}}}}}"#)
+        );
+        assert_eq!(
+            custom_json(cancel_binary_options_order_by_cid_msg(
+                sender,
+                market_id,
+                subaccount_id,
+                "cid-1".to_string(),
+                1
+            )),
+            format!(r#"{{"route":"exchange","msg_data":{{"cancel_binary_options_order":{{{ids},"order_hash":"","cid":"cid-1","order_mask":1}}}}}}"#)
+        );
+    }
+
+    #[test]
+    fn batch_update_cancels_by_cid_or_hash() {
+        let market_id = MarketId::unchecked(MARKET_ID);
+        let subaccount_id = SubaccountId::unchecked(SUBACCOUNT_ID);
+
+        let msg = create_batch_update_orders_msg(
+            Addr::unchecked(SENDER),
+            None,
+            vec![],
+            vec![],
+            vec![OrderData::from_cid(market_id.clone(), subaccount_id.clone(), "cid-1".to_string())],
+            vec![OrderData::new(market_id, subaccount_id, "0xabc".to_string())],
+            vec![],
+            vec![],
+        );
+
+        assert_eq!(
+            custom_json(msg),
+            format!(
+                r#"{{"route":"exchange","msg_data":{{"batch_update_orders":{{"sender":"{SENDER}","subaccount_id":null,"spot_market_ids_to_cancel_all":[],"derivative_market_ids_to_cancel_all":[],"spot_orders_to_cancel":[{{"market_id":"{MARKET_ID}","subaccount_id":"001","order_hash":"","cid":"cid-1"}}],"derivative_orders_to_cancel":[{{"market_id":"{MARKET_ID}","subaccount_id":"001","order_hash":"0xabc"}}],"spot_orders_to_create":[],"derivative_orders_to_create":[]}}}}}}"#
+            )
+        );
+    }
+
+    #[test]
+    fn register_contract_msg_is_only_built_for_valid_requests() {
+        let request = ContractRegistrationRequest::new(SENDER, 100_000, 500_000_000);
+
+        let msg = create_register_contract_msg(Addr::unchecked(SENDER), request.clone()).unwrap();
+        assert!(custom_json(msg).contains("\"register_contract\""));
+
+        let err = create_register_contract_msg(Addr::unchecked(SENDER), request.with_funding(FundingMode::GrantOnly, None)).unwrap_err();
+        assert!(matches!(err, InjectiveError::InvalidContractRegistration { .. }));
+    }
+}
@@ -1,3 +1,4 @@
+pub mod pyth;
 pub mod response;
 pub mod types;
 pub mod volatility;
@@ -0,0 +1,193 @@
+use cosmwasm_std::Timestamp;
+use injective_math::{scale::Scaled, FPDecimal};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::error::InjectiveError;
+use crate::oracle::types::{PriceAttestation, PythPriceState, PythStatus};
+
+/// Pyth price and confidence interval scaled to human units
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct PythPrice {
+    pub price_id: String,
+    pub price: FPDecimal,
+    pub conf: FPDecimal,
+    pub publish_time: i64,
+}
+
+impl PythPrice {
+    /// Seconds elapsed between the publish time and `now`, zero for prices published in the future
+    pub fn age(&self, now: Timestamp) -> u64 {
+        (now.seconds() as i64).saturating_sub(self.publish_time).max(0) as u64
+    }
+
+    /// Fails with `InvalidPythPrice` for prices that are not positive, like those of halted or blank feeds
+    pub fn confidence_ratio(&self) -> Result<FPDecimal, InjectiveError> {
+        self.ensure_positive()?;
+        Ok(self.conf / self.price)
+    }
+
+    pub fn ensure_positive(&self) -> Result<(), InjectiveError> {
+        if self.price <= FPDecimal::ZERO {
+            return Err(InjectiveError::InvalidPythPrice {
+                price_id: self.price_id.to_owned(),
+                price: self.price,
+            });
+        }
+        Ok(())
+    }
+
+    pub fn ensure_fresh(&self, now: Timestamp, max_age: u64) -> Result<(), InjectiveError> {
+        let age = self.age(now);
+        if age > max_age {
+            return Err(InjectiveError::StalePythPrice {
+                price_id: self.price_id.to_owned(),
+                age,
+                max_age,
+            });
+        }
+        Ok(())
+    }
+
+    pub fn ensure_confident(&self, max_confidence_ratio: FPDecimal) -> Result<(), InjectiveError> {
+        let confidence_ratio = self.confidence_ratio()?;
+        if confidence_ratio > max_confidence_ratio {
+            return Err(InjectiveError::PythConfidenceTooWide {
+                price_id: self.price_id.to_owned(),
+                confidence_ratio,
+                max_confidence_ratio,
+            });
+        }
+        Ok(())
+    }
+
+    /// Runs every check, the usual way to accept a price
+    pub fn validate(self, now: Timestamp, max_age: u64, max_confidence_ratio: FPDecimal) -> Result<Self, InjectiveError> {
+        self.ensure_fresh(now, max_age)?;
+        self.ensure_confident(max_confidence_ratio)?;
+        Ok(self)
+    }
+}
+
+impl PriceAttestation {
+    pub fn to_price(&self) -> PythPrice {
+        PythPrice {
+            price_id: self.price_id.to_owned(),
+            price: FPDecimal::from(i128::from(self.price)).scaled(self.expo),
+            conf: FPDecimal::from(u128::from(self.conf)).scaled(self.expo),
+            publish_time: self.publish_time,
+        }
+    }
+
+    pub fn to_ema_price(&self) -> PythPrice {
+        PythPrice {
+            price_id: self.price_id.to_owned(),
+            price: FPDecimal::from(i128::from(self.ema_price)).scaled(self.expo),
+            conf: FPDecimal::from(u128::from(self.ema_conf)).scaled(self.expo),
+            publish_time: self.publish_time,
+        }
+    }
+
+    pub fn ensure_trading(&self) -> Result<(), InjectiveError> {
+        match self.status {
+            PythStatus::Trading => Ok(()),
+            status => Err(InjectiveError::PythPriceNotTrading {
+                price_id: self.price_id.to_owned(),
+                status,
+            }),
+        }
+    }
+
+    pub fn validated_price(&self, now: Timestamp, max_age: u64, max_confidence_ratio: FPDecimal) -> Result<PythPrice, InjectiveError> {
+        self.ensure_trading()?;
+        self.to_price().validate(now, max_age, max_confidence_ratio)
+    }
+}
+
+impl PythPriceState {
+    /// The chain only stores prices of trading feeds, so no status is checked here
+    pub fn to_price(&self) -> PythPrice {
+        PythPrice {
+            price_id: self.price_id.to_owned(),
+            price: self.price_state.price,
+            conf: self.conf,
+            publish_time: self.publish_time,
+        }
+    }
+
+    pub fn validated_price(&self, now: Timestamp, max_age: u64, max_confidence_ratio: FPDecimal) -> Result<PythPrice, InjectiveError> {
+        self.to_price().validate(now, max_age, max_confidence_ratio)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::Timestamp;
+    use injective_math::FPDecimal;
+
+    use crate::error::InjectiveError;
+    use crate::oracle::types::{PriceAttestation, PythStatus};
+
+    fn attestation(status: PythStatus) -> PriceAttestation {
+        PriceAttestation {
+            product_id: "product".to_string(),
+            price_id: "0xf9c0172ba10dfa4d19088d94f5bf61d3b54d5bd7483a322a982e1373ee8ea31b".to_string(),
+            price: 2_512_345_000,
+            conf: 2_500_000,
+            expo: -8,
+            ema_price: 2_500_000_000,
+            ema_conf: 1_000_000,
+            status,
+            num_publishers: 10,
+            max_num_publishers: 20,
+            attestation_time: 1_700_000_000,
+            publish_time: 1_700_000_000,
+        }
+    }
+
+    #[test]
+    fn attestation_is_scaled_by_its_exponent() {
+        let attestation = attestation(PythStatus::Trading);
+        let price = attestation.to_price();
+
+        assert_eq!(price.price, FPDecimal::must_from_str("25.12345"));
+        assert_eq!(price.conf, FPDecimal::must_from_str("0.025"));
+        assert_eq!(attestation.to_ema_price().price, FPDecimal::must_from_str("25"));
+    }
+
+    #[test]
+    fn validated_price_rejects_halted_stale_and_wide_prices() {
+        let now = Timestamp::from_seconds(1_700_000_030);
+        let max_confidence_ratio = FPDecimal::must_from_str("0.01");
+
+        assert!(attestation(PythStatus::Trading).validated_price(now, 60, max_confidence_ratio).is_ok());
+        assert!(matches!(
+            attestation(PythStatus::Halted).validated_price(now, 60, max_confidence_ratio),
+            Err(InjectiveError::PythPriceNotTrading { .. })
+        ));
+        assert!(matches!(
+            attestation(PythStatus::Trading).validated_price(now, 10, max_confidence_ratio),
+            Err(InjectiveError::StalePythPrice { age: 30, max_age: 10, .. })
+        ));
+        assert!(matches!(
+            attestation(PythStatus::Trading).validated_price(now, 60, FPDecimal::must_from_str("0.0005")),
+            Err(InjectiveError::PythConfidenceTooWide { .. })
+        ));
+    }
+
+    #[test]
+    fn zero_prices_have_no_confidence_ratio() {
+        let mut price = attestation(PythStatus::Trading).to_price();
+        assert_eq!(
+            price.confidence_ratio(),
+            Ok(FPDecimal::must_from_str("0.025") / FPDecimal::must_from_str("25.12345"))
+        );
+
+        price.price = FPDecimal::ZERO;
+        assert!(matches!(price.confidence_ratio(), Err(InjectiveError::InvalidPythPrice { .. })));
+        assert!(matches!(
+            price.ensure_confident(FPDecimal::ONE),
+            Err(InjectiveError::InvalidPythPrice { .. })
+        ));
+    }
+}
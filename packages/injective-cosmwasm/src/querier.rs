@@ -1,14 +1,22 @@
+use crate::auction::response::{AuctionParamsResponse, CurrentAuctionBasketResponse};
+use crate::authz::{
+    response::{GranteeGrantsResponse, GranterGrantsResponse, GrantsResponse},
+    types::PageRequest,
+};
 use crate::exchange::{
     cancel::CancellationStrategy,
     order::OrderSide,
     response::{
-        DerivativeMarketResponse, ExchangeParamsResponse, MarketMidPriceAndTOBResponse, MarketVolatilityResponse, OracleVolatilityResponse,
-        PerpetualMarketFundingResponse, PerpetualMarketInfoResponse, QueryAggregateMarketVolumeResponse, QueryAggregateVolumeResponse,
+        BinaryOptionsMarketResponse, DerivativeMarketResponse, ExchangeParamsResponse, ExpiryFuturesMarketInfoResponse, MarketMidPriceAndTOBResponse,
+        MarketVolatilityResponse, OracleVolatilityResponse, PerpetualMarketFundingResponse, PerpetualMarketInfoResponse,
+        QueryAggregateMarketVolumeResponse, QueryAggregateVolumeResponse, QueryDenomDecimalResponse, QueryDenomDecimalsResponse,
         QueryMarketAtomicExecutionFeeMultiplierResponse, QueryOrderbookResponse, SpotMarketResponse, StakedAmountResponse, SubaccountDepositResponse,
-        SubaccountEffectivePositionInMarketResponse, SubaccountPositionInMarketResponse, TraderDerivativeOrdersResponse, TraderSpotOrdersResponse,
+        SubaccountDepositsResponse, SubaccountEffectivePositionInMarketResponse, SubaccountPositionInMarketResponse, SubaccountPositionsResponse,
+        SubaccountTradeNonceResponse, TraderDerivativeOrdersResponse, TraderSpotOrdersResponse,
     },
     types::{MarketId, SubaccountId},
 };
+use crate::insurance::response::InsuranceFundResponse;
 use crate::oracle::{
     response::{OraclePriceResponse, PythPriceResponse},
     types::{OracleHistoryOptions, OracleInfo, OracleType},
@@ -16,7 +24,10 @@ use crate::oracle::{
 };
 use crate::query::{InjectiveQuery, InjectiveQueryWrapper};
 use crate::route::InjectiveRoute;
-use crate::tokenfactory::response::{TokenFactoryCreateDenomFeeResponse, TokenFactoryDenomSupplyResponse};
+use crate::tokenfactory::response::{
+    TokenFactoryCreateDenomFeeResponse, TokenFactoryDenomAuthorityMetadataResponse, TokenFactoryDenomSupplyResponse,
+    TokenFactoryDenomsFromCreatorResponse,
+};
 use crate::wasmx::response::QueryContractRegistrationInfoResponse;
 use cosmwasm_std::{Addr, QuerierWrapper, StdResult};
 use injective_math::FPDecimal;
@@ -58,6 +69,30 @@ impl<'a> InjectiveQuerier<'a> {
         Ok(res)
     }
 
+    pub fn query_subaccount_deposits<T: Into<SubaccountId> + Clone>(&self, subaccount_id: &'a T) -> StdResult<SubaccountDepositsResponse> {
+        let request = InjectiveQueryWrapper {
+            route: InjectiveRoute::Exchange,
+            query_data: InjectiveQuery::SubaccountDeposits {
+                subaccount_id: subaccount_id.clone().into(),
+            },
+        };
+
+        let res: SubaccountDepositsResponse = self.querier.query(&request.into())?;
+        Ok(res)
+    }
+
+    pub fn query_subaccount_trade_nonce<T: Into<SubaccountId> + Clone>(&self, subaccount_id: &'a T) -> StdResult<SubaccountTradeNonceResponse> {
+        let request = InjectiveQueryWrapper {
+            route: InjectiveRoute::Exchange,
+            query_data: InjectiveQuery::SubaccountTradeNonce {
+                subaccount_id: subaccount_id.clone().into(),
+            },
+        };
+
+        let res: SubaccountTradeNonceResponse = self.querier.query(&request.into())?;
+        Ok(res)
+    }
+
     pub fn query_derivative_market<T: Into<MarketId> + Clone>(&self, market_id: &'a T) -> StdResult<DerivativeMarketResponse> {
         let request = InjectiveQueryWrapper {
             route: InjectiveRoute::Exchange,
@@ -82,6 +117,30 @@ impl<'a> InjectiveQuerier<'a> {
         Ok(res)
     }
 
+    pub fn query_binary_options_market<T: Into<MarketId> + Clone>(&self, market_id: &'a T) -> StdResult<BinaryOptionsMarketResponse> {
+        let request = InjectiveQueryWrapper {
+            route: InjectiveRoute::Exchange,
+            query_data: InjectiveQuery::BinaryOptionsMarket {
+                market_id: market_id.clone().into(),
+            },
+        };
+
+        let res: BinaryOptionsMarketResponse = self.querier.query(&request.into())?;
+        Ok(res)
+    }
+
+    pub fn query_subaccount_positions<T: Into<SubaccountId> + Clone>(&self, subaccount_id: &'a T) -> StdResult<SubaccountPositionsResponse> {
+        let request = InjectiveQueryWrapper {
+            route: InjectiveRoute::Exchange,
+            query_data: InjectiveQuery::SubaccountPositions {
+                subaccount_id: subaccount_id.clone().into(),
+            },
+        };
+
+        let res: SubaccountPositionsResponse = self.querier.query(&request.into())?;
+        Ok(res)
+    }
+
     pub fn query_effective_subaccount_position<T: Into<MarketId> + Clone, P: Into<SubaccountId> + Clone>(
         &self,
         market_id: &'a T,
@@ -256,6 +315,18 @@ impl<'a> InjectiveQuerier<'a> {
         Ok(res)
     }
 
+    pub fn query_expiry_futures_market_info<T: Into<MarketId> + Clone>(&self, market_id: &'a T) -> StdResult<ExpiryFuturesMarketInfoResponse> {
+        let request = InjectiveQueryWrapper {
+            route: InjectiveRoute::Exchange,
+            query_data: InjectiveQuery::ExpiryFuturesMarketInfo {
+                market_id: market_id.clone().into(),
+            },
+        };
+
+        let res: ExpiryFuturesMarketInfoResponse = self.querier.query(&request.into())?;
+        Ok(res)
+    }
+
     pub fn query_market_volatility<T: Into<MarketId> + Clone>(
         &self,
         market_id: &'a T,
@@ -381,6 +452,28 @@ impl<'a> InjectiveQuerier<'a> {
         Ok(res)
     }
 
+    pub fn query_denom_decimal<T: Into<String> + Clone>(&self, denom: &'a T) -> StdResult<QueryDenomDecimalResponse> {
+        let request = InjectiveQueryWrapper {
+            route: InjectiveRoute::Exchange,
+            query_data: InjectiveQuery::DenomDecimal { denom: denom.clone().into() },
+        };
+
+        let res: QueryDenomDecimalResponse = self.querier.query(&request.into())?;
+        Ok(res)
+    }
+
+    pub fn query_denom_decimals<T: Into<String> + Clone>(&self, denoms: &'a [T]) -> StdResult<QueryDenomDecimalsResponse> {
+        let request = InjectiveQueryWrapper {
+            route: InjectiveRoute::Exchange,
+            query_data: InjectiveQuery::DenomDecimals {
+                denoms: denoms.iter().cloned().map(Into::into).collect(),
+            },
+        };
+
+        let res: QueryDenomDecimalsResponse = self.querier.query(&request.into())?;
+        Ok(res)
+    }
+
     // Staking
     pub fn query_staked_amount(&self, delegator_address: Addr, max_delegations: u16) -> StdResult<StakedAmountResponse> {
         let request = InjectiveQueryWrapper {
@@ -468,6 +561,69 @@ impl<'a> InjectiveQuerier<'a> {
         Ok(res)
     }
 
+    pub fn query_denom_authority_metadata<T: Into<String> + Clone, P: Into<String> + Clone>(
+        &self,
+        creator: &'a T,
+        sub_denom: &'a P,
+    ) -> StdResult<TokenFactoryDenomAuthorityMetadataResponse> {
+        let request = InjectiveQueryWrapper {
+            route: InjectiveRoute::Tokenfactory,
+            query_data: InjectiveQuery::TokenFactoryDenomAuthorityMetadata {
+                creator: creator.clone().into(),
+                sub_denom: sub_denom.clone().into(),
+            },
+        };
+
+        let res: TokenFactoryDenomAuthorityMetadataResponse = self.querier.query(&request.into())?;
+        Ok(res)
+    }
+
+    pub fn query_denoms_from_creator<T: Into<String> + Clone>(&self, creator: &'a T) -> StdResult<TokenFactoryDenomsFromCreatorResponse> {
+        let request = InjectiveQueryWrapper {
+            route: InjectiveRoute::Tokenfactory,
+            query_data: InjectiveQuery::TokenFactoryDenomsFromCreator {
+                creator: creator.clone().into(),
+            },
+        };
+
+        let res: TokenFactoryDenomsFromCreatorResponse = self.querier.query(&request.into())?;
+        Ok(res)
+    }
+
+    // Auction
+    pub fn query_auction_params(&self) -> StdResult<AuctionParamsResponse> {
+        let request = InjectiveQueryWrapper {
+            route: InjectiveRoute::Auction,
+            query_data: InjectiveQuery::AuctionParams {},
+        };
+
+        let res: AuctionParamsResponse = self.querier.query(&request.into())?;
+        Ok(res)
+    }
+
+    pub fn query_current_auction_basket(&self) -> StdResult<CurrentAuctionBasketResponse> {
+        let request = InjectiveQueryWrapper {
+            route: InjectiveRoute::Auction,
+            query_data: InjectiveQuery::CurrentAuctionBasket {},
+        };
+
+        let res: CurrentAuctionBasketResponse = self.querier.query(&request.into())?;
+        Ok(res)
+    }
+
+    // Insurance
+    pub fn query_insurance_fund<T: Into<MarketId> + Clone>(&self, market_id: &'a T) -> StdResult<InsuranceFundResponse> {
+        let request = InjectiveQueryWrapper {
+            route: InjectiveRoute::Insurance,
+            query_data: InjectiveQuery::InsuranceFund {
+                market_id: market_id.clone().into(),
+            },
+        };
+
+        let res: InsuranceFundResponse = self.querier.query(&request.into())?;
+        Ok(res)
+    }
+
     // Wasmx
     pub fn query_contract_registration_info<A: Into<String> + Clone>(
         &self,
@@ -484,4 +640,46 @@ impl<'a> InjectiveQuerier<'a> {
 
         Ok(res)
     }
+
+    // Authz
+    pub fn query_grants(&self, granter: &str, grantee: &str, msg_type_url: &str, pagination: Option<PageRequest>) -> StdResult<GrantsResponse> {
+        let request = InjectiveQueryWrapper {
+            route: InjectiveRoute::Authz,
+            query_data: InjectiveQuery::Grants {
+                granter: granter.to_string(),
+                grantee: grantee.to_string(),
+                msg_type_url: msg_type_url.to_string(),
+                pagination,
+            },
+        };
+
+        let res: GrantsResponse = self.querier.query(&request.into())?;
+        Ok(res)
+    }
+
+    pub fn query_granter_grants(&self, granter: &str, pagination: Option<PageRequest>) -> StdResult<GranterGrantsResponse> {
+        let request = InjectiveQueryWrapper {
+            route: InjectiveRoute::Authz,
+            query_data: InjectiveQuery::GranterGrants {
+                granter: granter.to_string(),
+                pagination,
+            },
+        };
+
+        let res: GranterGrantsResponse = self.querier.query(&request.into())?;
+        Ok(res)
+    }
+
+    pub fn query_grantee_grants(&self, grantee: &str, pagination: Option<PageRequest>) -> StdResult<GranteeGrantsResponse> {
+        let request = InjectiveQueryWrapper {
+            route: InjectiveRoute::Authz,
+            query_data: InjectiveQuery::GranteeGrants {
+                grantee: grantee.to_string(),
+                pagination,
+            },
+        };
+
+        let res: GranteeGrantsResponse = self.querier.query(&request.into())?;
+        Ok(res)
+    }
 }
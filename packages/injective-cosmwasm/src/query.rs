@@ -4,6 +4,7 @@ use serde::{Deserialize, Serialize};
 
 use injective_math::FPDecimal;
 
+use crate::authz::types::PageRequest;
 use crate::exchange::{
     cancel::CancellationStrategy,
     order::OrderSide,
@@ -32,6 +33,12 @@ pub enum InjectiveQuery {
         subaccount_id: SubaccountId,
         denom: String,
     },
+    SubaccountDeposits {
+        subaccount_id: SubaccountId,
+    },
+    SubaccountTradeNonce {
+        subaccount_id: SubaccountId,
+    },
     SpotMarket {
         market_id: MarketId,
     },
@@ -86,6 +93,9 @@ pub enum InjectiveQuery {
     PerpetualMarketFunding {
         market_id: MarketId,
     },
+    ExpiryFuturesMarketInfo {
+        market_id: MarketId,
+    },
     // Make sure you are aware of the potential to run out of gas when using this query
     MarketVolatility {
         market_id: MarketId,
@@ -118,6 +128,15 @@ pub enum InjectiveQuery {
     MarketAtomicExecutionFeeMultiplier {
         market_id: MarketId,
     },
+    BinaryOptionsMarket {
+        market_id: MarketId,
+    },
+    DenomDecimal {
+        denom: String,
+    },
+    DenomDecimals {
+        denoms: Vec<String>,
+    },
     // Staking
     StakedAmount {
         delegator_address: Addr,
@@ -141,10 +160,39 @@ pub enum InjectiveQuery {
         denom: String,
     },
     TokenFactoryDenomCreationFee {},
+    TokenFactoryDenomAuthorityMetadata {
+        creator: String,
+        sub_denom: String,
+    },
+    TokenFactoryDenomsFromCreator {
+        creator: String,
+    },
+    // Auction
+    AuctionParams {},
+    CurrentAuctionBasket {},
+    // Insurance
+    InsuranceFund {
+        market_id: MarketId,
+    },
     // Wasmx
     WasmxRegisteredContractInfo {
         contract_address: String,
     },
+    // Authz
+    Grants {
+        granter: String,
+        grantee: String,
+        msg_type_url: String,
+        pagination: Option<PageRequest>,
+    },
+    GranterGrants {
+        granter: String,
+        pagination: Option<PageRequest>,
+    },
+    GranteeGrants {
+        grantee: String,
+        pagination: Option<PageRequest>,
+    },
 }
 
 impl CustomQuery for InjectiveQueryWrapper {}
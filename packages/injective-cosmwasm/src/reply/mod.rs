@@ -0,0 +1,367 @@
+mod proto;
+
+use std::str::FromStr;
+
+use cosmwasm_std::{Binary, Reply, StdError, StdResult};
+use injective_math::{scale::dec_scale_factor, FPDecimal};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::exchange::spot::{MsgCreateSpotMarketOrderResponse, SpotMarketOrderResults};
+use proto::{ProtoReader, WireValue};
+
+/// Exchange message response carried in the data of a submessage reply
+pub trait ExchangeReply: Sized {
+    fn decode(data: &[u8]) -> StdResult<Self>;
+
+    fn from_reply(reply: Reply) -> StdResult<Self> {
+        Self::decode(reply_data(reply)?.as_slice())
+    }
+}
+
+/// Extracts the data of a successful submessage reply
+pub fn reply_data(reply: Reply) -> StdResult<Binary> {
+    let id = reply.id;
+    reply
+        .result
+        .into_result()
+        .map_err(|err| StdError::generic_err(format!("Submessage {id} failed: {err}")))?
+        .data
+        .ok_or_else(|| StdError::generic_err(format!("Missing reply data for submessage {id}")))
+}
+
+/// Chain decimals are sent as integers scaled by 10^18
+fn decode_dec(value: &WireValue) -> StdResult<FPDecimal> {
+    let raw = value.as_string()?;
+    if raw.is_empty() {
+        return Ok(FPDecimal::ZERO);
+    }
+    Ok(FPDecimal::from_str(&raw)? / dec_scale_factor())
+}
+
+fn non_empty(value: String) -> Option<String> {
+    if value.is_empty() {
+        None
+    } else {
+        Some(value)
+    }
+}
+
+impl ExchangeReply for SpotMarketOrderResults {
+    fn decode(data: &[u8]) -> StdResult<Self> {
+        let mut results = SpotMarketOrderResults {
+            quantity: FPDecimal::ZERO,
+            price: FPDecimal::ZERO,
+            fee: FPDecimal::ZERO,
+        };
+
+        let mut reader = ProtoReader::new(data);
+        while let Some((field, value)) = reader.next_field()? {
+            match field {
+                1 => results.quantity = decode_dec(&value)?,
+                2 => results.price = decode_dec(&value)?,
+                3 => results.fee = decode_dec(&value)?,
+                _ => {}
+            }
+        }
+
+        Ok(results)
+    }
+}
+
+impl ExchangeReply for MsgCreateSpotMarketOrderResponse {
+    fn decode(data: &[u8]) -> StdResult<Self> {
+        let mut reply = MsgCreateSpotMarketOrderResponse {
+            order_hash: String::new(),
+            cid: None,
+            results: None,
+        };
+
+        let mut reader = ProtoReader::new(data);
+        while let Some((field, value)) = reader.next_field()? {
+            match field {
+                1 => reply.order_hash = value.as_string()?,
+                2 => reply.results = Some(SpotMarketOrderResults::decode(value.as_bytes()?)?),
+                3 => reply.cid = non_empty(value.as_string()?),
+                _ => {}
+            }
+        }
+
+        Ok(reply)
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct PositionDelta {
+    pub is_long: bool,
+    pub execution_quantity: FPDecimal,
+    pub execution_margin: FPDecimal,
+    pub execution_price: FPDecimal,
+}
+
+impl ExchangeReply for PositionDelta {
+    fn decode(data: &[u8]) -> StdResult<Self> {
+        let mut delta = PositionDelta {
+            is_long: false,
+            execution_quantity: FPDecimal::ZERO,
+            execution_margin: FPDecimal::ZERO,
+            execution_price: FPDecimal::ZERO,
+        };
+
+        let mut reader = ProtoReader::new(data);
+        while let Some((field, value)) = reader.next_field()? {
+            match field {
+                1 => delta.is_long = value.as_bool()?,
+                2 => delta.execution_quantity = decode_dec(&value)?,
+                3 => delta.execution_margin = decode_dec(&value)?,
+                4 => delta.execution_price = decode_dec(&value)?,
+                _ => {}
+            }
+        }
+
+        Ok(delta)
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct DerivativeMarketOrderResults {
+    pub quantity: FPDecimal,
+    pub price: FPDecimal,
+    pub fee: FPDecimal,
+    pub position_delta: Option<PositionDelta>,
+    pub payout: FPDecimal,
+}
+
+impl ExchangeReply for DerivativeMarketOrderResults {
+    fn decode(data: &[u8]) -> StdResult<Self> {
+        let mut results = DerivativeMarketOrderResults {
+            quantity: FPDecimal::ZERO,
+            price: FPDecimal::ZERO,
+            fee: FPDecimal::ZERO,
+            position_delta: None,
+            payout: FPDecimal::ZERO,
+        };
+
+        let mut reader = ProtoReader::new(data);
+        while let Some((field, value)) = reader.next_field()? {
+            match field {
+                1 => results.quantity = decode_dec(&value)?,
+                2 => results.price = decode_dec(&value)?,
+                3 => results.fee = decode_dec(&value)?,
+                4 => results.position_delta = Some(PositionDelta::decode(value.as_bytes()?)?),
+                5 => results.payout = decode_dec(&value)?,
+                _ => {}
+            }
+        }
+
+        Ok(results)
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct DerivativeMarketOrderReply {
+    pub order_hash: String,
+    pub cid: Option<String>,
+    pub results: Option<DerivativeMarketOrderResults>,
+}
+
+impl ExchangeReply for DerivativeMarketOrderReply {
+    fn decode(data: &[u8]) -> StdResult<Self> {
+        let mut reply = DerivativeMarketOrderReply {
+            order_hash: String::new(),
+            cid: None,
+            results: None,
+        };
+
+        let mut reader = ProtoReader::new(data);
+        while let Some((field, value)) = reader.next_field()? {
+            match field {
+                1 => reply.order_hash = value.as_string()?,
+                2 => reply.results = Some(DerivativeMarketOrderResults::decode(value.as_bytes()?)?),
+                3 => reply.cid = non_empty(value.as_string()?),
+                _ => {}
+            }
+        }
+
+        Ok(reply)
+    }
+}
+
+/// Response of both spot and derivative limit order creation
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct LimitOrderReply {
+    pub order_hash: String,
+    pub cid: Option<String>,
+}
+
+impl ExchangeReply for LimitOrderReply {
+    fn decode(data: &[u8]) -> StdResult<Self> {
+        let mut reply = LimitOrderReply {
+            order_hash: String::new(),
+            cid: None,
+        };
+
+        let mut reader = ProtoReader::new(data);
+        while let Some((field, value)) = reader.next_field()? {
+            match field {
+                1 => reply.order_hash = value.as_string()?,
+                2 => reply.cid = non_empty(value.as_string()?),
+                _ => {}
+            }
+        }
+
+        Ok(reply)
+    }
+}
+
+/// Response of both spot and derivative batch limit order creation
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq, JsonSchema)]
+pub struct BatchCreateLimitOrdersReply {
+    pub order_hashes: Vec<String>,
+    pub created_orders_cids: Vec<String>,
+    pub failed_orders_cids: Vec<String>,
+}
+
+impl ExchangeReply for BatchCreateLimitOrdersReply {
+    fn decode(data: &[u8]) -> StdResult<Self> {
+        let mut reply = BatchCreateLimitOrdersReply::default();
+
+        let mut reader = ProtoReader::new(data);
+        while let Some((field, value)) = reader.next_field()? {
+            match field {
+                1 => reply.order_hashes.push(value.as_string()?),
+                2 => reply.created_orders_cids.push(value.as_string()?),
+                3 => reply.failed_orders_cids.push(value.as_string()?),
+                _ => {}
+            }
+        }
+
+        Ok(reply)
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq, JsonSchema)]
+pub struct BatchUpdateOrdersReply {
+    pub spot_cancel_success: Vec<bool>,
+    pub derivative_cancel_success: Vec<bool>,
+    pub spot_order_hashes: Vec<String>,
+    pub derivative_order_hashes: Vec<String>,
+    pub binary_options_cancel_success: Vec<bool>,
+    pub binary_options_order_hashes: Vec<String>,
+    pub created_spot_orders_cids: Vec<String>,
+    pub failed_spot_orders_cids: Vec<String>,
+    pub created_derivative_orders_cids: Vec<String>,
+    pub failed_derivative_orders_cids: Vec<String>,
+    pub created_binary_options_orders_cids: Vec<String>,
+    pub failed_binary_options_orders_cids: Vec<String>,
+}
+
+impl ExchangeReply for BatchUpdateOrdersReply {
+    fn decode(data: &[u8]) -> StdResult<Self> {
+        let mut reply = BatchUpdateOrdersReply::default();
+
+        let mut reader = ProtoReader::new(data);
+        while let Some((field, value)) = reader.next_field()? {
+            match field {
+                1 => value.push_bools(&mut reply.spot_cancel_success)?,
+                2 => value.push_bools(&mut reply.derivative_cancel_success)?,
+                3 => reply.spot_order_hashes.push(value.as_string()?),
+                4 => reply.derivative_order_hashes.push(value.as_string()?),
+                5 => value.push_bools(&mut reply.binary_options_cancel_success)?,
+                6 => reply.binary_options_order_hashes.push(value.as_string()?),
+                7 => reply.created_spot_orders_cids.push(value.as_string()?),
+                8 => reply.failed_spot_orders_cids.push(value.as_string()?),
+                9 => reply.created_derivative_orders_cids.push(value.as_string()?),
+                10 => reply.failed_derivative_orders_cids.push(value.as_string()?),
+                11 => reply.created_binary_options_orders_cids.push(value.as_string()?),
+                12 => reply.failed_binary_options_orders_cids.push(value.as_string()?),
+                _ => {}
+            }
+        }
+
+        Ok(reply)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{Binary, Reply, SubMsgResponse, SubMsgResult};
+    use injective_math::FPDecimal;
+
+    use crate::exchange::spot::{MsgCreateSpotMarketOrderResponse, SpotMarketOrderResults};
+    use crate::reply::{BatchUpdateOrdersReply, ExchangeReply, LimitOrderReply};
+
+    #[test]
+    fn decode_spot_market_order_reply() {
+        let data = Binary::from_base64("CkIweGRkNzI5MmY2ODcwMzIwOTc2YTUxYTUwODBiMGQ2NDU5M2NhZjE3OWViM2YxOTNjZWVlZGFiNGVhNWUxNDljZWISQwoTODAwMDAwMDAwMDAwMDAwMDAwMBIWMTAwMDAwMDAwMDAwMDAwMDAwMDAwMBoUMzYwMDAwMDAwMDAwMDAwMDAwMDA=").unwrap();
+        let reply = Reply {
+            id: 1,
+            result: SubMsgResult::Ok(SubMsgResponse {
+                events: vec![],
+                data: Some(data),
+            }),
+        };
+
+        let response = MsgCreateSpotMarketOrderResponse::from_reply(reply).unwrap();
+        assert_eq!(
+            response,
+            MsgCreateSpotMarketOrderResponse {
+                order_hash: "0xdd7292f6870320976a51a5080b0d64593caf179eb3f193ceeedab4ea5e149ceb".to_string(),
+                cid: None,
+                results: Some(SpotMarketOrderResults {
+                    quantity: FPDecimal::from(8u128),
+                    price: FPDecimal::from(1000u128),
+                    fee: FPDecimal::from(36u128),
+                }),
+            }
+        );
+    }
+
+    #[test]
+    fn missing_spot_market_order_results_decode_as_none() {
+        let mut data = vec![0x0a, 0x04];
+        data.extend_from_slice(b"0xab");
+
+        let response = MsgCreateSpotMarketOrderResponse::decode(&data).unwrap();
+        assert_eq!(response.order_hash, "0xab");
+        assert_eq!(response.results, None);
+    }
+
+    #[test]
+    fn decode_limit_order_reply_with_cid() {
+        let mut data = vec![0x0a, 0x04];
+        data.extend_from_slice(b"0xab");
+        data.extend_from_slice(&[0x12, 0x03]);
+        data.extend_from_slice(b"cid");
+
+        let response = LimitOrderReply::decode(&data).unwrap();
+        assert_eq!(response.order_hash, "0xab");
+        assert_eq!(response.cid, Some("cid".to_string()));
+    }
+
+    #[test]
+    fn decode_batch_update_orders_reply() {
+        // packed spot_cancel_success, unpacked derivative_cancel_success, one spot hash and one created spot cid
+        let mut data = vec![0x0a, 0x02, 0x01, 0x00, 0x10, 0x01, 0x1a, 0x04];
+        data.extend_from_slice(b"0xab");
+        data.extend_from_slice(&[0x3a, 0x03]);
+        data.extend_from_slice(b"cid");
+
+        let response = BatchUpdateOrdersReply::decode(&data).unwrap();
+        assert_eq!(response.spot_cancel_success, vec![true, false]);
+        assert_eq!(response.derivative_cancel_success, vec![true]);
+        assert_eq!(response.spot_order_hashes, vec!["0xab".to_string()]);
+        assert_eq!(response.created_spot_orders_cids, vec!["cid".to_string()]);
+    }
+
+    #[test]
+    fn failed_and_truncated_replies_are_errors() {
+        let reply = Reply {
+            id: 1,
+            result: SubMsgResult::Err("out of funds".to_string()),
+        };
+        assert!(MsgCreateSpotMarketOrderResponse::from_reply(reply).is_err());
+
+        assert!(LimitOrderReply::decode(&[0x0a, 0x10, 0x30]).is_err());
+    }
+}
@@ -0,0 +1,115 @@
+use cosmwasm_std::{StdError, StdResult};
+
+/// Value of a single protobuf field, only length-delimited and varint payloads are kept
+pub(crate) enum WireValue<'a> {
+    Varint(u64),
+    Bytes(&'a [u8]),
+    Fixed,
+}
+
+impl<'a> WireValue<'a> {
+    pub fn as_string(&self) -> StdResult<String> {
+        match self {
+            WireValue::Bytes(bytes) => String::from_utf8(bytes.to_vec()).map_err(|e| StdError::parse_err("String", e.to_string())),
+            _ => Err(StdError::parse_err("String", "expected length-delimited field")),
+        }
+    }
+
+    pub fn as_bytes(&self) -> StdResult<&'a [u8]> {
+        match self {
+            WireValue::Bytes(bytes) => Ok(bytes),
+            _ => Err(StdError::parse_err("bytes", "expected length-delimited field")),
+        }
+    }
+
+    pub fn as_bool(&self) -> StdResult<bool> {
+        match self {
+            WireValue::Varint(value) => Ok(*value != 0),
+            _ => Err(StdError::parse_err("bool", "expected varint field")),
+        }
+    }
+
+    /// Appends a repeated bool field, which proto3 encodes packed by default
+    pub fn push_bools(&self, out: &mut Vec<bool>) -> StdResult<()> {
+        match self {
+            WireValue::Varint(value) => out.push(*value != 0),
+            WireValue::Bytes(bytes) => {
+                let mut reader = ProtoReader::new(bytes);
+                while !reader.is_empty() {
+                    out.push(reader.read_varint()? != 0);
+                }
+            }
+            WireValue::Fixed => return Err(StdError::parse_err("bool", "expected varint field")),
+        }
+        Ok(())
+    }
+}
+
+pub(crate) struct ProtoReader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> ProtoReader<'a> {
+    pub fn new(data: &'a [u8]) -> Self {
+        ProtoReader { data, pos: 0 }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pos >= self.data.len()
+    }
+
+    /// Returns the next field number and its value, or `None` once all data has been read
+    pub fn next_field(&mut self) -> StdResult<Option<(u64, WireValue<'a>)>> {
+        if self.is_empty() {
+            return Ok(None);
+        }
+
+        let key = self.read_varint()?;
+        let value = match key & 0x7 {
+            0 => WireValue::Varint(self.read_varint()?),
+            1 => {
+                self.take(8)?;
+                WireValue::Fixed
+            }
+            2 => {
+                let len = self.read_varint()? as usize;
+                WireValue::Bytes(self.take(len)?)
+            }
+            5 => {
+                self.take(4)?;
+                WireValue::Fixed
+            }
+            wire_type => return Err(StdError::parse_err("protobuf", format!("unsupported wire type {wire_type}"))),
+        };
+
+        Ok(Some((key >> 3, value)))
+    }
+
+    fn read_varint(&mut self) -> StdResult<u64> {
+        let mut result = 0u64;
+        for shift in (0..64).step_by(7) {
+            let byte = *self
+                .data
+                .get(self.pos)
+                .ok_or_else(|| StdError::parse_err("protobuf", "unexpected end of varint"))?;
+            self.pos += 1;
+            result |= u64::from(byte & 0x7f) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(result);
+            }
+        }
+        Err(StdError::parse_err("protobuf", "varint overflow"))
+    }
+
+    fn take(&mut self, len: usize) -> StdResult<&'a [u8]> {
+        let end = self
+            .pos
+            .checked_add(len)
+            .filter(|end| *end <= self.data.len())
+            .ok_or_else(|| StdError::parse_err("protobuf", "field length exceeds data"))?;
+        let bytes = &self.data[self.pos..end];
+        self.pos = end;
+        Ok(bytes)
+    }
+}
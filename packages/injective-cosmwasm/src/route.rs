@@ -5,8 +5,10 @@ use serde::{Deserialize, Serialize};
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum InjectiveRoute {
+    Auction,
     Authz,
     Exchange,
+    Insurance,
     Tokenfactory,
     Staking,
     Oracle,
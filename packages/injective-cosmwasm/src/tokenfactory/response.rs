@@ -2,6 +2,8 @@ use cosmwasm_std::{Coin, Uint128};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tokenfactory::types::DenomAuthorityMetadata;
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 pub struct TokenFactoryDenomSupplyResponse {
     pub total_supply: Uint128,
@@ -11,3 +13,14 @@ pub struct TokenFactoryDenomSupplyResponse {
 pub struct TokenFactoryCreateDenomFeeResponse {
     pub fee: Vec<Coin>,
 }
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct TokenFactoryDenomAuthorityMetadataResponse {
+    pub authority_metadata: DenomAuthorityMetadata,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct TokenFactoryDenomsFromCreatorResponse {
+    #[serde(default)]
+    pub denoms: Vec<String>,
+}
@@ -0,0 +1,131 @@
+use std::{fmt, str::FromStr};
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use subtle_encoding::bech32;
+
+use crate::error::InjectiveError;
+
+const FACTORY_PREFIX: &str = "factory";
+const MAX_SUBDENOM_LENGTH: usize = 44;
+const MAX_CREATOR_LENGTH: usize = 59 + 16;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct DenomAuthorityMetadata {
+    #[serde(default)]
+    pub admin: String,
+}
+
+/// A `factory/{creator}/{subdenom}` denom, validated with the same rules as the tokenfactory module
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TokenFactoryDenom {
+    creator: String,
+    subdenom: String,
+}
+
+impl TokenFactoryDenom {
+    pub fn new(creator: impl Into<String>, subdenom: impl Into<String>) -> Result<Self, InjectiveError> {
+        let denom = TokenFactoryDenom {
+            creator: creator.into(),
+            subdenom: subdenom.into(),
+        };
+        denom.validate()?;
+        Ok(denom)
+    }
+
+    pub fn creator(&self) -> &str {
+        &self.creator
+    }
+
+    pub fn subdenom(&self) -> &str {
+        &self.subdenom
+    }
+
+    fn validate(&self) -> Result<(), InjectiveError> {
+        let invalid = |reason: &str| InjectiveError::InvalidTokenFactoryDenom {
+            denom: self.to_string(),
+            reason: reason.to_string(),
+        };
+
+        if self.subdenom.len() > MAX_SUBDENOM_LENGTH {
+            return Err(invalid("subdenom too long"));
+        }
+        if self.creator.len() > MAX_CREATOR_LENGTH {
+            return Err(invalid("creator too long"));
+        }
+
+        let (hrp, _) = bech32::decode(&self.creator).map_err(|e| invalid(&format!("invalid creator: {e}")))?;
+        if hrp != "inj" {
+            return Err(invalid("creator is not an inj address"));
+        }
+
+        let valid_chars = self.subdenom.chars().all(|c| c.is_ascii_alphanumeric() || "/:._-".contains(c));
+        if !valid_chars {
+            return Err(invalid("subdenom contains invalid characters"));
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for TokenFactoryDenom {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}/{}", FACTORY_PREFIX, self.creator, self.subdenom)
+    }
+}
+
+impl FromStr for TokenFactoryDenom {
+    type Err = InjectiveError;
+
+    fn from_str(denom: &str) -> Result<Self, Self::Err> {
+        let invalid = |reason: &str| InjectiveError::InvalidTokenFactoryDenom {
+            denom: denom.to_string(),
+            reason: reason.to_string(),
+        };
+
+        // subdenoms may themselves contain slashes
+        let mut parts = denom.splitn(3, '/');
+        if parts.next() != Some(FACTORY_PREFIX) {
+            return Err(invalid("missing factory prefix"));
+        }
+
+        match (parts.next(), parts.next()) {
+            (Some(creator), Some(subdenom)) => TokenFactoryDenom::new(creator, subdenom),
+            _ => Err(invalid("expected factory/{creator}/{subdenom}")),
+        }
+    }
+}
+
+impl From<TokenFactoryDenom> for String {
+    fn from(denom: TokenFactoryDenom) -> Self {
+        denom.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::tokenfactory::types::TokenFactoryDenom;
+
+    const CREATOR: &str = "inj1cml96vmptgw99syqrrz8az79xer2pcgp0a885r";
+
+    #[test]
+    fn parses_factory_denoms() {
+        let denom = TokenFactoryDenom::from_str(&format!("factory/{CREATOR}/lp/usdt")).unwrap();
+
+        assert_eq!(denom.creator(), CREATOR);
+        assert_eq!(denom.subdenom(), "lp/usdt");
+        assert_eq!(denom.to_string(), format!("factory/{CREATOR}/lp/usdt"));
+    }
+
+    #[test]
+    fn rejects_invalid_denoms() {
+        assert!(TokenFactoryDenom::from_str("inj").is_err());
+        assert!(TokenFactoryDenom::from_str(&format!("peggy/{CREATOR}/usdt")).is_err());
+        assert!(TokenFactoryDenom::from_str(&format!("factory/{CREATOR}")).is_err());
+        assert!(TokenFactoryDenom::from_str("factory/not_an_address/usdt").is_err());
+        assert!(TokenFactoryDenom::new(CREATOR, "a".repeat(45)).is_err());
+        assert!(TokenFactoryDenom::new(CREATOR, "usd t").is_err());
+    }
+}
@@ -0,0 +1,27 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Sudo messages sent by the wasmx module to registered contracts
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum WasmxSudoMsg {
+    /// Sent every block while the contract is registered and active
+    BeginBlocker {},
+    /// Sent when the contract is deactivated, e.g. after running out of gas funds
+    Deactivate {},
+    /// Sent when the contract registration is removed
+    Deregister {},
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{from_json, to_json_string};
+
+    use crate::wasmx::msg::WasmxSudoMsg;
+
+    #[test]
+    fn sudo_msgs_use_chain_json() {
+        assert_eq!(to_json_string(&WasmxSudoMsg::BeginBlocker {}).unwrap(), r#"{"begin_blocker":{}}"#);
+        assert_eq!(from_json::<WasmxSudoMsg>(r#"{"deregister":{}}"#).unwrap(), WasmxSudoMsg::Deregister {});
+    }
+}
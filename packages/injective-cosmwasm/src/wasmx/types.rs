@@ -2,7 +2,9 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use serde_repr::{Deserialize_repr, Serialize_repr};
 
-#[derive(Serialize_repr, Deserialize_repr, Clone, Debug, PartialEq, Eq, JsonSchema)]
+use crate::error::InjectiveError;
+
+#[derive(Serialize_repr, Deserialize_repr, Clone, Copy, Debug, PartialEq, Eq, JsonSchema)]
 #[repr(i32)]
 pub enum FundingMode {
     Unspecified = 0,
@@ -11,6 +13,93 @@ pub enum FundingMode {
     Dual = 3,
 }
 
+impl FundingMode {
+    /// GrantOnly and Dual contracts pay BeginBlocker gas from a fee grant of the granter
+    pub fn requires_granter(&self) -> bool {
+        matches!(self, FundingMode::GrantOnly | FundingMode::Dual)
+    }
+}
+
+/// Registration of a contract for execution in BeginBlocker, see [`crate::create_register_contract_msg`]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct ContractRegistrationRequest {
+    pub contract_address: String,
+    // limit of gas per BB execution
+    pub gas_limit: u64,
+    // gas price that contract is willing to pay for execution in BeginBlocker
+    pub gas_price: u64,
+    pub should_pin_contract: bool,
+    pub is_migration_allowed: bool,
+    // code_id that is allowed to be executed, ignored when migrations are allowed
+    pub code_id: u64,
+    // optional - admin addr that is allowed to update contract data
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub admin_address: String,
+    // must be set if funding_mode is GrantOnly or Dual
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub granter_address: String,
+    pub funding_mode: FundingMode,
+}
+
+impl ContractRegistrationRequest {
+    /// Self funded registration of a contract whose code id may change on migration
+    pub fn new(contract_address: impl Into<String>, gas_limit: u64, gas_price: u64) -> Self {
+        ContractRegistrationRequest {
+            contract_address: contract_address.into(),
+            gas_limit,
+            gas_price,
+            should_pin_contract: false,
+            is_migration_allowed: true,
+            code_id: 0,
+            admin_address: "".to_string(),
+            granter_address: "".to_string(),
+            funding_mode: FundingMode::SelfFunded,
+        }
+    }
+
+    /// Only executes `code_id`, a migration deregisters the contract
+    pub fn with_code_id(mut self, code_id: u64) -> Self {
+        self.code_id = code_id;
+        self.is_migration_allowed = false;
+        self
+    }
+
+    pub fn with_admin(mut self, admin_address: impl Into<String>) -> Self {
+        self.admin_address = admin_address.into();
+        self
+    }
+
+    pub fn with_pinned_contract(mut self) -> Self {
+        self.should_pin_contract = true;
+        self
+    }
+
+    pub fn with_funding(mut self, funding_mode: FundingMode, granter_address: Option<String>) -> Self {
+        self.funding_mode = funding_mode;
+        self.granter_address = granter_address.unwrap_or_default();
+        self
+    }
+
+    pub fn validate(&self) -> Result<(), InjectiveError> {
+        let invalid = |reason: String| InjectiveError::InvalidContractRegistration {
+            contract_address: self.contract_address.to_owned(),
+            reason,
+        };
+
+        if self.gas_limit == 0 || self.gas_price == 0 {
+            return Err(invalid("gas limit and gas price must be positive".to_string()));
+        }
+        if self.funding_mode == FundingMode::Unspecified {
+            return Err(invalid("funding mode must be specified".to_string()));
+        }
+        if self.funding_mode.requires_granter() && self.granter_address.is_empty() {
+            return Err(invalid(format!("funding mode {:?} requires a granter address", self.funding_mode)));
+        }
+
+        Ok(())
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 pub struct RegisteredContract {
     // limit of gas per BB execution
@@ -29,3 +118,39 @@ pub struct RegisteredContract {
     /// funding mode
     pub fund_mode: FundingMode,
 }
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::to_json_string;
+
+    use crate::wasmx::types::{ContractRegistrationRequest, FundingMode};
+
+    const CONTRACT: &str = "inj1cml96vmptgw99syqrrz8az79xer2pcgp0a885r";
+
+    #[test]
+    fn grant_funded_registration_requires_granter() {
+        let request = ContractRegistrationRequest::new(CONTRACT, 1_000_000, 500_000_000);
+        assert!(request.validate().is_ok());
+        assert!(request.clone().with_funding(FundingMode::GrantOnly, None).validate().is_err());
+        assert!(request
+            .clone()
+            .with_funding(FundingMode::Dual, Some(CONTRACT.to_string()))
+            .validate()
+            .is_ok());
+        assert!(ContractRegistrationRequest::new(CONTRACT, 0, 500_000_000).validate().is_err());
+    }
+
+    #[test]
+    fn registration_serializes_funding_mode_as_number() {
+        let request = ContractRegistrationRequest::new(CONTRACT, 1_000_000, 500_000_000)
+            .with_code_id(7)
+            .with_funding(FundingMode::GrantOnly, Some(CONTRACT.to_string()));
+
+        assert_eq!(
+            to_json_string(&request).unwrap(),
+            format!(
+                r#"{{"contract_address":"{CONTRACT}","gas_limit":1000000,"gas_price":500000000,"should_pin_contract":false,"is_migration_allowed":false,"code_id":7,"granter_address":"{CONTRACT}","funding_mode":2}}"#
+            )
+        );
+    }
+}
@@ -13,7 +13,7 @@ use std::{
     u8,
 };
 
-use crate::InjectiveAddressGenerator;
+use crate::{ExchangeKeeper, InjectiveAddressGenerator, TokenFactoryKeeper};
 
 fn no_init<BankT, CustomT, WasmT, StakingT, DistrT, IbcT, GovT>(
     _: &mut Router<BankT, CustomT, WasmT, StakingT, DistrT, IbcT, GovT>,
@@ -190,6 +190,22 @@ pub struct CustomInjectiveHandler {
     pub responses: CustomInjectiveHandlerResponses,
     pub assertions: CustomInjectiveHandlerAssertions<InjectiveMsgWrapper, InjectiveQueryWrapper>,
     pub enable_debug: bool,
+    /// Opt-in emulation of the exchange module's subaccount deposits, see [`ExchangeKeeper`]
+    pub exchange: Option<ExchangeKeeper>,
+    /// Opt-in emulation of the tokenfactory module, see [`TokenFactoryKeeper`]
+    pub tokenfactory: Option<TokenFactoryKeeper>,
+}
+
+impl CustomInjectiveHandler {
+    pub fn with_exchange_keeper(mut self) -> Self {
+        self.exchange = Some(ExchangeKeeper);
+        self
+    }
+
+    pub fn with_tokenfactory_keeper(mut self, creation_fee: Vec<Coin>) -> Self {
+        self.tokenfactory = Some(TokenFactoryKeeper::new(creation_fee));
+        self
+    }
 }
 
 impl Module for CustomInjectiveHandler {
@@ -199,13 +215,16 @@ impl Module for CustomInjectiveHandler {
 
     fn execute<ExecC, QueryC>(
         &self,
-        _api: &dyn Api,
-        _storage: &mut dyn Storage,
-        _router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
-        _block: &BlockInfo,
-        _sender: Addr,
+        api: &dyn Api,
+        storage: &mut dyn Storage,
+        router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+        block: &BlockInfo,
+        sender: Addr,
         msg: Self::ExecT,
-    ) -> AnyResult<AppResponse> {
+    ) -> AnyResult<AppResponse>
+    where
+        QueryC: CustomQuery,
+    {
         let mut exec_calls_count = self.state.execs.borrow().len();
 
         if !self.assertions.executes.is_empty()
@@ -222,6 +241,18 @@ impl Module for CustomInjectiveHandler {
             println!("[{exec_calls_count}] Execute message: {msg:?}");
         }
 
+        if let Some(exchange) = &self.exchange {
+            if let Some(result) = exchange.execute(api, storage, router, block, &sender, &msg.msg_data) {
+                return result;
+            }
+        }
+
+        if let Some(tokenfactory) = &self.tokenfactory {
+            if let Some(result) = tokenfactory.execute(api, storage, router, block, &sender, &msg.msg_data) {
+                return result;
+            }
+        }
+
         if self.responses.executes.is_empty()
             || exec_calls_count > self.responses.executes.len()
             || self.responses.executes[exec_calls_count - 1].is_empty()
@@ -245,7 +276,7 @@ impl Module for CustomInjectiveHandler {
         }
     }
 
-    fn query(&self, _api: &dyn Api, _storage: &dyn Storage, _querier: &dyn Querier, _block: &BlockInfo, request: Self::QueryT) -> AnyResult<Binary> {
+    fn query(&self, _api: &dyn Api, storage: &dyn Storage, querier: &dyn Querier, _block: &BlockInfo, request: Self::QueryT) -> AnyResult<Binary> {
         let mut query_calls_count = self.state.queries.borrow().len();
 
         if !self.assertions.queries.is_empty()
@@ -262,6 +293,18 @@ impl Module for CustomInjectiveHandler {
             println!("[{query_calls_count}] Query request: {request:?}");
         }
 
+        if let Some(exchange) = &self.exchange {
+            if let Some(result) = exchange.query(storage, querier, &request.query_data) {
+                return result;
+            }
+        }
+
+        if let Some(tokenfactory) = &self.tokenfactory {
+            if let Some(result) = tokenfactory.query(storage, &request.query_data) {
+                return result;
+            }
+        }
+
         if self.responses.queries.is_empty()
             || query_calls_count > self.responses.queries.len()
             || self.responses.queries[query_calls_count - 1].is_empty()
@@ -331,6 +374,23 @@ pub fn mock_custom_injective_chain_app(
         })
 }
 
+/// App answering with the keepers enabled on `inj_handler`, e.g. `CustomInjectiveHandler::default().with_exchange_keeper().with_tokenfactory_keeper(vec![])`
+pub fn mock_injective_chain_app_with_handler(initial_balances: Vec<InitialBalance>, inj_handler: CustomInjectiveHandler) -> MockedInjectiveApp {
+    let inj_wasm_keeper = WasmKeeper::<InjectiveMsgWrapper, InjectiveQueryWrapper>::new_with_custom_address_generator(InjectiveAddressGenerator());
+
+    BasicAppBuilder::new()
+        .with_custom(inj_handler)
+        .with_wasm::<CustomInjectiveHandler, WasmKeeper<InjectiveMsgWrapper, InjectiveQueryWrapper>>(inj_wasm_keeper)
+        .build(|router, _, storage| {
+            initial_balances.into_iter().for_each(|balance| {
+                router
+                    .bank
+                    .init_balance(storage, &balance.address, balance.amounts)
+                    .expect("balances added")
+            })
+        })
+}
+
 pub fn mock_default_injective_chain_app() -> MockedInjectiveApp {
     let inj_wasm_keeper = WasmKeeper::<InjectiveMsgWrapper, InjectiveQueryWrapper>::new_with_custom_address_generator(InjectiveAddressGenerator());
 
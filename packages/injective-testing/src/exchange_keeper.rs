@@ -0,0 +1,518 @@
+use std::collections::BTreeMap;
+
+use anyhow::{bail, ensure, Result as AnyResult};
+use cosmwasm_std::{
+    from_json, to_json_binary, to_json_vec, Addr, Api, BankMsg, Binary, BlockInfo, Coin, CustomQuery, Empty, Order, Querier, QuerierWrapper, Storage,
+};
+use cw_multi_test::{AppResponse, CosmosRouter};
+use injective_cosmwasm::{
+    is_default_subaccount, subaccount_id_to_ethereum_address, try_addr_to_bech32, try_bech32_to_hex, Deposit, InjectiveError, InjectiveMsg,
+    InjectiveQuery, SubaccountDepositResponse, SubaccountDepositsResponse, SubaccountId,
+};
+use injective_math::FPDecimal;
+
+use crate::keeper_utils::{ensure_sender, prefix_range_end};
+
+/// Bank account of the exchange module, holding the funds deposited into subaccounts
+pub const EXCHANGE_MODULE_ADDRESS: &str = "inj14vnmw2wee3xtrsqfvpcqg35jg9v7j2vdpzx0kk";
+
+const DEPOSITS_PREFIX: &str = "injective-testing/exchange/deposits/";
+
+/// Emulates the subaccount accounting of the exchange module. `Deposit`, `Withdraw`, `SubaccountTransfer` and `ExternalTransfer`
+/// move funds between bank balances and subaccount deposits, and `SubaccountDeposit` / `SubaccountDeposits` are answered from the
+/// keeper's state. Default subaccounts hold no deposits since their funds are the owner's bank balance, which is what
+/// `create_subaccount_transfer_msg` and `create_external_transfer_msg` assume, so their deposits are answered from the bank like on chain.
+///
+/// Deposits are kept in the app storage, so they are rolled back with the rest of a failed transaction.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ExchangeKeeper;
+
+impl ExchangeKeeper {
+    /// Returns `None` for messages the keeper does not emulate
+    pub fn execute<ExecC, QueryC>(
+        &self,
+        api: &dyn Api,
+        storage: &mut dyn Storage,
+        router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+        block: &BlockInfo,
+        sender: &Addr,
+        msg: &InjectiveMsg,
+    ) -> Option<AnyResult<AppResponse>>
+    where
+        QueryC: CustomQuery,
+    {
+        let result = match msg {
+            InjectiveMsg::Deposit {
+                sender: msg_sender,
+                subaccount_id,
+                amount,
+            } => ensure_sender(sender, msg_sender.as_str()).and_then(|_| self.deposit(api, storage, router, block, sender, subaccount_id, amount)),
+            InjectiveMsg::Withdraw {
+                sender: msg_sender,
+                subaccount_id,
+                amount,
+            } => ensure_sender(sender, msg_sender.as_str()).and_then(|_| self.withdraw(api, storage, router, block, sender, subaccount_id, amount)),
+            InjectiveMsg::SubaccountTransfer {
+                sender: msg_sender,
+                source_subaccount_id,
+                destination_subaccount_id,
+                amount,
+            } => ensure_sender(sender, msg_sender.as_str()).and_then(|_| {
+                self.subaccount_transfer(
+                    api,
+                    storage,
+                    router,
+                    block,
+                    sender,
+                    source_subaccount_id,
+                    destination_subaccount_id,
+                    amount,
+                )
+            }),
+            InjectiveMsg::ExternalTransfer {
+                sender: msg_sender,
+                source_subaccount_id,
+                destination_subaccount_id,
+                amount,
+            } => ensure_sender(sender, msg_sender.as_str())
+                .and_then(|_| self.external_transfer(storage, sender, source_subaccount_id, destination_subaccount_id, amount)),
+            _ => return None,
+        };
+
+        Some(result)
+    }
+
+    /// Returns `None` for queries the keeper does not answer
+    pub fn query(&self, storage: &dyn Storage, querier: &dyn Querier, request: &InjectiveQuery) -> Option<AnyResult<Binary>> {
+        let result = match request {
+            InjectiveQuery::SubaccountDeposit { subaccount_id, denom } if is_default_subaccount(subaccount_id) => {
+                bank_deposit(querier, subaccount_id, denom).and_then(|deposits| Ok(to_json_binary(&SubaccountDepositResponse { deposits })?))
+            }
+            InjectiveQuery::SubaccountDeposit { subaccount_id, denom } => self
+                .subaccount_deposit(storage, subaccount_id, denom)
+                .and_then(|deposits| Ok(to_json_binary(&SubaccountDepositResponse { deposits })?)),
+            InjectiveQuery::SubaccountDeposits { subaccount_id } if is_default_subaccount(subaccount_id) => {
+                bank_deposits(querier, subaccount_id).and_then(|deposits| Ok(to_json_binary(&SubaccountDepositsResponse { deposits })?))
+            }
+            InjectiveQuery::SubaccountDeposits { subaccount_id } => self
+                .subaccount_deposits(storage, subaccount_id)
+                .and_then(|deposits| Ok(to_json_binary(&SubaccountDepositsResponse { deposits })?)),
+            _ => return None,
+        };
+
+        Some(result)
+    }
+
+    pub fn subaccount_deposit(&self, storage: &dyn Storage, subaccount_id: &SubaccountId, denom: &str) -> AnyResult<Deposit> {
+        match storage.get(&deposit_key(subaccount_id, denom)) {
+            Some(value) => Ok(from_json(value)?),
+            None => Ok(Deposit {
+                available_balance: FPDecimal::ZERO,
+                total_balance: FPDecimal::ZERO,
+            }),
+        }
+    }
+
+    pub fn subaccount_deposits(&self, storage: &dyn Storage, subaccount_id: &SubaccountId) -> AnyResult<BTreeMap<String, Deposit>> {
+        let prefix = deposits_prefix(subaccount_id);
+        let end = prefix_range_end(&prefix);
+
+        storage
+            .range(Some(&prefix), Some(&end), Order::Ascending)
+            .map(|(key, value)| -> AnyResult<(String, Deposit)> { Ok((String::from_utf8(key[prefix.len()..].to_vec())?, from_json(value)?)) })
+            .collect()
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn deposit<ExecC, QueryC>(
+        &self,
+        api: &dyn Api,
+        storage: &mut dyn Storage,
+        router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+        block: &BlockInfo,
+        sender: &Addr,
+        subaccount_id: &SubaccountId,
+        amount: &Coin,
+    ) -> AnyResult<AppResponse>
+    where
+        QueryC: CustomQuery,
+    {
+        ensure_owner(sender, subaccount_id)?;
+        ensure!(
+            !is_default_subaccount(subaccount_id),
+            "cannot deposit into default subaccount {}, its funds are bank balances",
+            subaccount_id.as_str()
+        );
+
+        let response = router.execute(
+            api,
+            storage,
+            block,
+            sender.to_owned(),
+            BankMsg::Send {
+                to_address: EXCHANGE_MODULE_ADDRESS.to_string(),
+                amount: vec![amount.to_owned()],
+            }
+            .into(),
+        )?;
+
+        let mut deposit = self.subaccount_deposit(storage, subaccount_id, &amount.denom)?;
+        deposit.available_balance += FPDecimal::from(amount.amount);
+        deposit.total_balance += FPDecimal::from(amount.amount);
+        set_deposit(storage, subaccount_id, &amount.denom, &deposit)?;
+
+        Ok(response)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn withdraw<ExecC, QueryC>(
+        &self,
+        api: &dyn Api,
+        storage: &mut dyn Storage,
+        router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+        block: &BlockInfo,
+        sender: &Addr,
+        subaccount_id: &SubaccountId,
+        amount: &Coin,
+    ) -> AnyResult<AppResponse>
+    where
+        QueryC: CustomQuery,
+    {
+        ensure_owner(sender, subaccount_id)?;
+        ensure!(
+            !is_default_subaccount(subaccount_id),
+            "cannot withdraw from default subaccount {}, its funds are bank balances",
+            subaccount_id.as_str()
+        );
+
+        self.debit(storage, subaccount_id, amount)?;
+
+        router.execute(
+            api,
+            storage,
+            block,
+            Addr::unchecked(EXCHANGE_MODULE_ADDRESS),
+            BankMsg::Send {
+                to_address: sender.to_string(),
+                amount: vec![amount.to_owned()],
+            }
+            .into(),
+        )
+    }
+
+    /// A transfer out of a default subaccount moves the owner's bank balance like a `Deposit`,
+    /// `create_subaccount_transfer_msg` sends one when funding a subaccount from the default subaccount
+    #[allow(clippy::too_many_arguments)]
+    fn subaccount_transfer<ExecC, QueryC>(
+        &self,
+        api: &dyn Api,
+        storage: &mut dyn Storage,
+        router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+        block: &BlockInfo,
+        sender: &Addr,
+        source_subaccount_id: &SubaccountId,
+        destination_subaccount_id: &SubaccountId,
+        amount: &Coin,
+    ) -> AnyResult<AppResponse>
+    where
+        QueryC: CustomQuery,
+    {
+        ensure_owner(sender, source_subaccount_id)?;
+        ensure_owner(sender, destination_subaccount_id)?;
+        ensure!(
+            !is_default_subaccount(destination_subaccount_id),
+            "cannot transfer to default subaccount {}, use Withdraw",
+            destination_subaccount_id.as_str()
+        );
+
+        if is_default_subaccount(source_subaccount_id) {
+            return self.deposit(api, storage, router, block, sender, destination_subaccount_id, amount);
+        }
+
+        self.transfer(storage, source_subaccount_id, destination_subaccount_id, amount)
+    }
+
+    fn external_transfer(
+        &self,
+        storage: &mut dyn Storage,
+        sender: &Addr,
+        source_subaccount_id: &SubaccountId,
+        destination_subaccount_id: &SubaccountId,
+        amount: &Coin,
+    ) -> AnyResult<AppResponse> {
+        ensure_owner(sender, source_subaccount_id)?;
+        if is_default_subaccount(source_subaccount_id) {
+            bail!(InjectiveError::ExternalTransferFromDefaultSubaccount);
+        }
+        ensure!(
+            !is_default_subaccount(destination_subaccount_id),
+            "cannot transfer to default subaccount {}, withdraw and send through the bank instead",
+            destination_subaccount_id.as_str()
+        );
+
+        self.transfer(storage, source_subaccount_id, destination_subaccount_id, amount)
+    }
+
+    fn transfer(
+        &self,
+        storage: &mut dyn Storage,
+        source_subaccount_id: &SubaccountId,
+        destination_subaccount_id: &SubaccountId,
+        amount: &Coin,
+    ) -> AnyResult<AppResponse> {
+        self.debit(storage, source_subaccount_id, amount)?;
+
+        let mut deposit = self.subaccount_deposit(storage, destination_subaccount_id, &amount.denom)?;
+        deposit.available_balance += FPDecimal::from(amount.amount);
+        deposit.total_balance += FPDecimal::from(amount.amount);
+        set_deposit(storage, destination_subaccount_id, &amount.denom, &deposit)?;
+
+        Ok(AppResponse::default())
+    }
+
+    fn debit(&self, storage: &mut dyn Storage, subaccount_id: &SubaccountId, amount: &Coin) -> AnyResult<()> {
+        let value = FPDecimal::from(amount.amount);
+        let mut deposit = self.subaccount_deposit(storage, subaccount_id, &amount.denom)?;
+        ensure!(
+            deposit.available_balance >= value,
+            "insufficient available balance in subaccount {}: {} {} required, {} available",
+            subaccount_id.as_str(),
+            value,
+            amount.denom,
+            deposit.available_balance
+        );
+
+        deposit.available_balance -= value;
+        deposit.total_balance -= value;
+        set_deposit(storage, subaccount_id, &amount.denom, &deposit)
+    }
+}
+
+fn ensure_owner(sender: &Addr, subaccount_id: &SubaccountId) -> AnyResult<()> {
+    let owner = subaccount_id_to_ethereum_address(subaccount_id);
+    ensure!(
+        owner.eq_ignore_ascii_case(&try_bech32_to_hex(sender)?),
+        "subaccount {} does not belong to {}",
+        subaccount_id.as_str(),
+        sender
+    );
+    Ok(())
+}
+
+fn owner_address(subaccount_id: &SubaccountId) -> AnyResult<String> {
+    Ok(try_addr_to_bech32(&subaccount_id_to_ethereum_address(subaccount_id))?)
+}
+
+fn bank_deposit(querier: &dyn Querier, subaccount_id: &SubaccountId, denom: &str) -> AnyResult<Deposit> {
+    let balance = QuerierWrapper::<Empty>::new(querier).query_balance(owner_address(subaccount_id)?, denom)?;
+    Ok(coin_to_deposit(&balance))
+}
+
+fn bank_deposits(querier: &dyn Querier, subaccount_id: &SubaccountId) -> AnyResult<BTreeMap<String, Deposit>> {
+    let balances = QuerierWrapper::<Empty>::new(querier).query_all_balances(owner_address(subaccount_id)?)?;
+    Ok(balances
+        .iter()
+        .map(|balance| (balance.denom.to_owned(), coin_to_deposit(balance)))
+        .collect())
+}
+
+fn coin_to_deposit(coin: &Coin) -> Deposit {
+    Deposit {
+        available_balance: FPDecimal::from(coin.amount),
+        total_balance: FPDecimal::from(coin.amount),
+    }
+}
+
+fn deposits_prefix(subaccount_id: &SubaccountId) -> Vec<u8> {
+    format!("{}{}/", DEPOSITS_PREFIX, subaccount_id.as_str()).into_bytes()
+}
+
+fn deposit_key(subaccount_id: &SubaccountId, denom: &str) -> Vec<u8> {
+    let mut key = deposits_prefix(subaccount_id);
+    key.extend_from_slice(denom.as_bytes());
+    key
+}
+
+fn set_deposit(storage: &mut dyn Storage, subaccount_id: &SubaccountId, denom: &str, deposit: &Deposit) -> AnyResult<()> {
+    storage.set(&deposit_key(subaccount_id, denom), &to_json_vec(deposit)?);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{coin, Addr, CosmosMsg};
+    use cw_multi_test::Executor;
+    use injective_cosmwasm::{
+        checked_address_to_subaccount_id, create_deposit_msg, create_subaccount_transfer_msg, create_withdraw_msg, inj_mock_deps, InjectiveMsg,
+        InjectiveMsgWrapper, InjectiveQuerier, InjectiveRoute, SubaccountId,
+    };
+    use injective_math::FPDecimal;
+
+    use crate::{mock_injective_chain_app_with_handler, CustomInjectiveHandler, InitialBalance, MockedInjectiveApp};
+
+    const TRADER: &str = "inj1cml96vmptgw99syqrrz8az79xer2pcgp0a885r";
+    const RECIPIENT: &str = "inj1khsfhyavadcvzug67pufytaz2cq36ljkrsr0nv";
+
+    fn app_with_trader() -> (MockedInjectiveApp, Addr) {
+        let trader = Addr::unchecked(TRADER);
+        let app = mock_injective_chain_app_with_handler(
+            vec![InitialBalance {
+                amounts: vec![coin(1_000, "inj")],
+                address: trader.clone(),
+            }],
+            CustomInjectiveHandler::default().with_exchange_keeper(),
+        );
+        (app, trader)
+    }
+
+    fn available_balance(app: &MockedInjectiveApp, subaccount_id: &SubaccountId) -> FPDecimal {
+        let querier = app.wrap();
+        let querier = InjectiveQuerier::new(&querier);
+        querier
+            .query_subaccount_deposit(subaccount_id, &"inj")
+            .unwrap()
+            .deposits
+            .available_balance
+    }
+
+    fn transfer_msg(
+        sender: &Addr,
+        source_subaccount_id: &SubaccountId,
+        destination_subaccount_id: &SubaccountId,
+        external: bool,
+    ) -> CosmosMsg<InjectiveMsgWrapper> {
+        let (sender, source_subaccount_id, destination_subaccount_id, amount) = (
+            sender.to_owned(),
+            source_subaccount_id.to_owned(),
+            destination_subaccount_id.to_owned(),
+            coin(100, "inj"),
+        );
+        let msg_data = if external {
+            InjectiveMsg::ExternalTransfer {
+                sender,
+                source_subaccount_id,
+                destination_subaccount_id,
+                amount,
+            }
+        } else {
+            InjectiveMsg::SubaccountTransfer {
+                sender,
+                source_subaccount_id,
+                destination_subaccount_id,
+                amount,
+            }
+        };
+
+        InjectiveMsgWrapper {
+            route: InjectiveRoute::Exchange,
+            msg_data,
+        }
+        .into()
+    }
+
+    #[test]
+    fn deposit_moves_bank_funds_into_subaccount() {
+        let (mut app, trader) = app_with_trader();
+        let subaccount_id = checked_address_to_subaccount_id(&trader, 1);
+
+        app.execute(
+            trader.clone(),
+            create_deposit_msg(trader.clone(), subaccount_id.clone(), coin(400, "inj")),
+        )
+        .unwrap();
+
+        assert_eq!(available_balance(&app, &subaccount_id), FPDecimal::from(400u128));
+        assert_eq!(app.wrap().query_balance(&trader, "inj").unwrap().amount.u128(), 600);
+        // the default subaccount is the bank balance
+        assert_eq!(
+            available_balance(&app, &checked_address_to_subaccount_id(&trader, 0)),
+            FPDecimal::from(600u128)
+        );
+    }
+
+    #[test]
+    fn withdrawing_more_than_available_fails() {
+        let (mut app, trader) = app_with_trader();
+        let subaccount_id = checked_address_to_subaccount_id(&trader, 1);
+        app.execute(
+            trader.clone(),
+            create_deposit_msg(trader.clone(), subaccount_id.clone(), coin(400, "inj")),
+        )
+        .unwrap();
+
+        let err = app
+            .execute(
+                trader.clone(),
+                create_withdraw_msg(trader.clone(), subaccount_id.clone(), coin(401, "inj")),
+            )
+            .unwrap_err();
+
+        assert!(err.root_cause().to_string().contains("insufficient available balance"), "{err:?}");
+        assert_eq!(available_balance(&app, &subaccount_id), FPDecimal::from(400u128));
+    }
+
+    #[test]
+    fn subaccount_transfer_moves_deposits_and_external_transfer_from_default_subaccount_is_rejected() {
+        let (mut app, trader) = app_with_trader();
+        let first_subaccount_id = checked_address_to_subaccount_id(&trader, 1);
+        let second_subaccount_id = checked_address_to_subaccount_id(&trader, 2);
+        app.execute(
+            trader.clone(),
+            create_deposit_msg(trader.clone(), first_subaccount_id.clone(), coin(400, "inj")),
+        )
+        .unwrap();
+
+        app.execute(trader.clone(), transfer_msg(&trader, &first_subaccount_id, &second_subaccount_id, false))
+            .unwrap();
+
+        assert_eq!(available_balance(&app, &first_subaccount_id), FPDecimal::from(300u128));
+        assert_eq!(available_balance(&app, &second_subaccount_id), FPDecimal::from(100u128));
+
+        let default_subaccount_id = checked_address_to_subaccount_id(&trader, 0);
+        let recipient_subaccount_id = checked_address_to_subaccount_id(&Addr::unchecked(RECIPIENT), 1);
+        let err = app
+            .execute(
+                trader.clone(),
+                transfer_msg(&trader, &default_subaccount_id, &recipient_subaccount_id, true),
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            err.root_cause().to_string(),
+            injective_cosmwasm::InjectiveError::ExternalTransferFromDefaultSubaccount.to_string()
+        );
+        assert_eq!(available_balance(&app, &recipient_subaccount_id), FPDecimal::ZERO);
+    }
+
+    #[test]
+    fn subaccount_transfer_from_default_subaccount_moves_bank_funds() {
+        let (mut app, trader) = app_with_trader();
+        let default_subaccount_id = checked_address_to_subaccount_id(&trader, 0);
+        let subaccount_id = checked_address_to_subaccount_id(&trader, 1);
+
+        let deps = inj_mock_deps(|_| {});
+        let msgs = create_subaccount_transfer_msg(&deps.as_ref(), &default_subaccount_id, &subaccount_id, &coin(250, "inj")).unwrap();
+        app.execute_multi(trader.clone(), msgs).unwrap();
+
+        assert_eq!(app.wrap().query_balance(&trader, "inj").unwrap().amount.u128(), 750);
+        assert_eq!(available_balance(&app, &subaccount_id), FPDecimal::from(250u128));
+    }
+
+    #[test]
+    fn failed_transaction_rolls_back_deposits() {
+        let (mut app, trader) = app_with_trader();
+        let subaccount_id = checked_address_to_subaccount_id(&trader, 1);
+
+        app.execute_multi(
+            trader.clone(),
+            vec![
+                create_deposit_msg(trader.clone(), subaccount_id.clone(), coin(400, "inj")),
+                create_withdraw_msg(trader.clone(), subaccount_id.clone(), coin(500, "inj")),
+            ],
+        )
+        .unwrap_err();
+
+        assert_eq!(available_balance(&app, &subaccount_id), FPDecimal::ZERO);
+        assert_eq!(app.wrap().query_balance(&trader, "inj").unwrap().amount.u128(), 1_000);
+    }
+}
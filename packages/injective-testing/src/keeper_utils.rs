@@ -0,0 +1,22 @@
+use anyhow::{ensure, Result as AnyResult};
+use cosmwasm_std::Addr;
+
+/// Keepers only execute messages signed by the address the app executes them for
+pub(crate) fn ensure_sender(sender: &Addr, msg_sender: &str) -> AnyResult<()> {
+    ensure!(
+        sender.as_str() == msg_sender,
+        "message sender {} does not match the executing address {}",
+        msg_sender,
+        sender
+    );
+    Ok(())
+}
+
+/// First key after every key starting with `prefix`, the exclusive end of a prefix range.
+/// The prefixes read by the keepers end with a printable byte, so incrementing it never overflows.
+pub(crate) fn prefix_range_end(prefix: &[u8]) -> Vec<u8> {
+    let mut end = prefix.to_vec();
+    let last = end.pop().expect("empty storage prefix");
+    end.push(last + 1);
+    end
+}
@@ -1,7 +1,12 @@
 mod address_generator;
 mod chain_mock;
+mod exchange_keeper;
+mod keeper_utils;
+mod tokenfactory_keeper;
 pub mod utils;
 
 pub use address_generator::{generate_inj_address, InjectiveAddressGenerator, StorageAwareInjectiveAddressGenerator};
 pub use chain_mock::*;
+pub use exchange_keeper::{ExchangeKeeper, EXCHANGE_MODULE_ADDRESS};
+pub use tokenfactory_keeper::{TokenFactoryKeeper, TokenMetadata, COMMUNITY_POOL_ADDRESS};
 pub use utils::*;
@@ -0,0 +1,417 @@
+use anyhow::{bail, ensure, Result as AnyResult};
+use cosmwasm_std::{from_json, to_json_binary, to_json_vec, Addr, Api, BankMsg, Binary, BlockInfo, Coin, CustomQuery, Order, Storage, Uint128};
+use cw_multi_test::{AppResponse, BankSudo, CosmosRouter, SudoMsg};
+use injective_cosmwasm::{
+    tokenfactory::response::{
+        TokenFactoryCreateDenomFeeResponse, TokenFactoryDenomAuthorityMetadataResponse, TokenFactoryDenomSupplyResponse,
+        TokenFactoryDenomsFromCreatorResponse,
+    },
+    DenomAuthorityMetadata, InjectiveMsg, InjectiveQuery, TokenFactoryDenom,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::keeper_utils::{ensure_sender, prefix_range_end};
+
+/// Distribution module account, the community pool receives the denom creation fees
+pub const COMMUNITY_POOL_ADDRESS: &str = "inj1jv65s3grqf6v6jl3dp4t6c9t9rk99cd8dkncm8";
+
+const ADMINS_PREFIX: &str = "injective-testing/tokenfactory/admins/";
+const METADATA_PREFIX: &str = "injective-testing/tokenfactory/metadata/";
+// balances of cw-multi-test's `BankKeeper`, a `Map<&Addr, NativeBalance>` named "balances" in its length-prefixed "bank" namespace
+const BANK_BALANCES_PREFIX: &[u8] = b"\x00\x04bank\x00\x08balances";
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct TokenMetadata {
+    pub name: String,
+    pub symbol: String,
+    pub decimals: u8,
+}
+
+/// Emulates the tokenfactory module. `CreateDenom` charges the creation fee and registers `factory/{creator}/{subdenom}` with the
+/// creator as admin, `Mint` and `Burn` go through the bank module and are restricted to the admin, like `ChangeAdmin` and
+/// `SetTokenMetadata`. Creation fee, authority metadata and denoms by creator queries are answered from the keeper's state, the total
+/// supply from the bank balances so that it cannot drift from them.
+///
+/// State is kept in the app storage, so it is rolled back with the rest of a failed transaction.
+#[derive(Clone, Debug, Default)]
+pub struct TokenFactoryKeeper {
+    pub creation_fee: Vec<Coin>,
+}
+
+impl TokenFactoryKeeper {
+    pub fn new(creation_fee: Vec<Coin>) -> Self {
+        TokenFactoryKeeper { creation_fee }
+    }
+
+    /// Returns `None` for messages the keeper does not emulate
+    pub fn execute<ExecC, QueryC>(
+        &self,
+        api: &dyn Api,
+        storage: &mut dyn Storage,
+        router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+        block: &BlockInfo,
+        sender: &Addr,
+        msg: &InjectiveMsg,
+    ) -> Option<AnyResult<AppResponse>>
+    where
+        QueryC: CustomQuery,
+    {
+        let result = match msg {
+            InjectiveMsg::CreateDenom {
+                sender: msg_sender,
+                subdenom,
+            } => ensure_sender(sender, msg_sender).and_then(|_| self.create_denom(api, storage, router, block, sender, subdenom)),
+            InjectiveMsg::Mint {
+                sender: msg_sender,
+                amount,
+                mint_to,
+            } => ensure_sender(sender, msg_sender.as_str()).and_then(|_| self.mint(api, storage, router, block, sender, amount, mint_to)),
+            InjectiveMsg::Burn { sender: msg_sender, amount } => {
+                ensure_sender(sender, msg_sender.as_str()).and_then(|_| self.burn(api, storage, router, block, sender, amount))
+            }
+            InjectiveMsg::ChangeAdmin {
+                sender: msg_sender,
+                denom,
+                new_admin_address,
+            } => ensure_sender(sender, msg_sender.as_str()).and_then(|_| self.change_admin(storage, sender, denom, new_admin_address)),
+            InjectiveMsg::SetTokenMetadata {
+                denom,
+                name,
+                symbol,
+                decimals,
+            } => self.set_token_metadata(
+                storage,
+                sender,
+                denom,
+                TokenMetadata {
+                    name: name.to_owned(),
+                    symbol: symbol.to_owned(),
+                    decimals: *decimals,
+                },
+            ),
+            _ => return None,
+        };
+
+        Some(result)
+    }
+
+    /// Returns `None` for queries the keeper does not answer
+    pub fn query(&self, storage: &dyn Storage, request: &InjectiveQuery) -> Option<AnyResult<Binary>> {
+        let result = match request {
+            InjectiveQuery::TokenFactoryDenomTotalSupply { denom } => self
+                .total_supply(storage, denom)
+                .and_then(|total_supply| Ok(to_json_binary(&TokenFactoryDenomSupplyResponse { total_supply })?)),
+            InjectiveQuery::TokenFactoryDenomCreationFee {} => to_json_binary(&TokenFactoryCreateDenomFeeResponse {
+                fee: self.creation_fee.to_owned(),
+            })
+            .map_err(Into::into),
+            InjectiveQuery::TokenFactoryDenomAuthorityMetadata { creator, sub_denom } => {
+                let denom = format!("factory/{}/{}", creator, sub_denom);
+                self.admin(storage, &denom).and_then(|admin| {
+                    Ok(to_json_binary(&TokenFactoryDenomAuthorityMetadataResponse {
+                        authority_metadata: DenomAuthorityMetadata { admin },
+                    })?)
+                })
+            }
+            InjectiveQuery::TokenFactoryDenomsFromCreator { creator } => self
+                .denoms_from_creator(storage, creator)
+                .and_then(|denoms| Ok(to_json_binary(&TokenFactoryDenomsFromCreatorResponse { denoms })?)),
+            _ => return None,
+        };
+
+        Some(result)
+    }
+
+    /// Sum of the bank balances in `denom`, zero for denoms that were never created like on chain. cw-multi-test 0.16's
+    /// `BankKeeper` does not answer `BankQuery::Supply`, so the balances are read from its storage.
+    pub fn total_supply(&self, storage: &dyn Storage, denom: &str) -> AnyResult<Uint128> {
+        let end = prefix_range_end(BANK_BALANCES_PREFIX);
+
+        storage
+            .range_values(Some(BANK_BALANCES_PREFIX), Some(&end), Order::Ascending)
+            .try_fold(Uint128::zero(), |total_supply, value| {
+                let balances: Vec<Coin> = from_json(value)?;
+                let amount = balances
+                    .iter()
+                    .find(|coin| coin.denom == denom)
+                    .map(|coin| coin.amount)
+                    .unwrap_or_default();
+                Ok(total_supply.checked_add(amount)?)
+            })
+    }
+
+    /// Empty once the admin rights were renounced
+    pub fn admin(&self, storage: &dyn Storage, denom: &str) -> AnyResult<String> {
+        match storage.get(&key(ADMINS_PREFIX, denom)) {
+            Some(value) => Ok(from_json(value)?),
+            None => bail!("denom {} does not exist", denom),
+        }
+    }
+
+    pub fn token_metadata(&self, storage: &dyn Storage, denom: &str) -> AnyResult<Option<TokenMetadata>> {
+        match storage.get(&key(METADATA_PREFIX, denom)) {
+            Some(value) => Ok(Some(from_json(value)?)),
+            None => Ok(None),
+        }
+    }
+
+    pub fn denoms_from_creator(&self, storage: &dyn Storage, creator: &str) -> AnyResult<Vec<String>> {
+        let prefix = key(ADMINS_PREFIX, &format!("factory/{}/", creator));
+        let end = prefix_range_end(&prefix);
+
+        storage
+            .range_keys(Some(&prefix), Some(&end), Order::Ascending)
+            .map(|key| -> AnyResult<String> { Ok(String::from_utf8(key[ADMINS_PREFIX.len()..].to_vec())?) })
+            .collect()
+    }
+
+    fn create_denom<ExecC, QueryC>(
+        &self,
+        api: &dyn Api,
+        storage: &mut dyn Storage,
+        router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+        block: &BlockInfo,
+        sender: &Addr,
+        subdenom: &str,
+    ) -> AnyResult<AppResponse>
+    where
+        QueryC: CustomQuery,
+    {
+        let denom = TokenFactoryDenom::new(sender.as_str(), subdenom)?.to_string();
+        ensure!(storage.get(&key(ADMINS_PREFIX, &denom)).is_none(), "denom {} already exists", denom);
+
+        let response = if self.creation_fee.is_empty() {
+            AppResponse::default()
+        } else {
+            router.execute(
+                api,
+                storage,
+                block,
+                sender.to_owned(),
+                BankMsg::Send {
+                    to_address: COMMUNITY_POOL_ADDRESS.to_string(),
+                    amount: self.creation_fee.to_owned(),
+                }
+                .into(),
+            )?
+        };
+
+        storage.set(&key(ADMINS_PREFIX, &denom), &to_json_vec(sender.as_str())?);
+
+        Ok(response)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn mint<ExecC, QueryC>(
+        &self,
+        api: &dyn Api,
+        storage: &mut dyn Storage,
+        router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+        block: &BlockInfo,
+        sender: &Addr,
+        amount: &Coin,
+        mint_to: &str,
+    ) -> AnyResult<AppResponse>
+    where
+        QueryC: CustomQuery,
+    {
+        self.ensure_admin(storage, sender, &amount.denom)?;
+
+        router.sudo(
+            api,
+            storage,
+            block,
+            SudoMsg::Bank(BankSudo::Mint {
+                to_address: mint_to.to_string(),
+                amount: vec![amount.to_owned()],
+            }),
+        )
+    }
+
+    /// Burns from the admin's own balance, the only source the module accepts
+    fn burn<ExecC, QueryC>(
+        &self,
+        api: &dyn Api,
+        storage: &mut dyn Storage,
+        router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+        block: &BlockInfo,
+        sender: &Addr,
+        amount: &Coin,
+    ) -> AnyResult<AppResponse>
+    where
+        QueryC: CustomQuery,
+    {
+        self.ensure_admin(storage, sender, &amount.denom)?;
+
+        router.execute(
+            api,
+            storage,
+            block,
+            sender.to_owned(),
+            BankMsg::Burn {
+                amount: vec![amount.to_owned()],
+            }
+            .into(),
+        )
+    }
+
+    fn change_admin(&self, storage: &mut dyn Storage, sender: &Addr, denom: &str, new_admin_address: &str) -> AnyResult<AppResponse> {
+        self.ensure_admin(storage, sender, denom)?;
+        storage.set(&key(ADMINS_PREFIX, denom), &to_json_vec(new_admin_address)?);
+
+        Ok(AppResponse::default())
+    }
+
+    fn set_token_metadata(&self, storage: &mut dyn Storage, sender: &Addr, denom: &str, metadata: TokenMetadata) -> AnyResult<AppResponse> {
+        self.ensure_admin(storage, sender, denom)?;
+        storage.set(&key(METADATA_PREFIX, denom), &to_json_vec(&metadata)?);
+
+        Ok(AppResponse::default())
+    }
+
+    fn ensure_admin(&self, storage: &dyn Storage, sender: &Addr, denom: &str) -> AnyResult<()> {
+        let admin = self.admin(storage, denom)?;
+        ensure!(
+            admin == sender.as_str(),
+            "{} is not the admin of {}, the admin is '{}'",
+            sender,
+            denom,
+            admin
+        );
+        Ok(())
+    }
+}
+
+fn key(prefix: &str, denom: &str) -> Vec<u8> {
+    format!("{}{}", prefix, denom).into_bytes()
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{coin, Addr, BankMsg, Uint128};
+    use cw_multi_test::Executor;
+    use injective_cosmwasm::{create_burn_tokens_msg, create_change_admin_msg, create_mint_tokens_msg, create_new_denom_msg, InjectiveQuerier};
+
+    use crate::{mock_injective_chain_app_with_handler, CustomInjectiveHandler, InitialBalance, MockedInjectiveApp, COMMUNITY_POOL_ADDRESS};
+
+    const CREATOR: &str = "inj1cml96vmptgw99syqrrz8az79xer2pcgp0a885r";
+    const HOLDER: &str = "inj1khsfhyavadcvzug67pufytaz2cq36ljkrsr0nv";
+    const DENOM: &str = "factory/inj1cml96vmptgw99syqrrz8az79xer2pcgp0a885r/coin";
+
+    fn app_with_denom() -> MockedInjectiveApp {
+        let creator = Addr::unchecked(CREATOR);
+        let mut app = mock_injective_chain_app_with_handler(
+            vec![InitialBalance {
+                amounts: vec![coin(1_000, "inj")],
+                address: creator.clone(),
+            }],
+            CustomInjectiveHandler::default().with_tokenfactory_keeper(vec![coin(10, "inj")]),
+        );
+        app.execute(creator, create_new_denom_msg(CREATOR.to_string(), "coin".to_string()))
+            .unwrap();
+        app
+    }
+
+    fn total_supply(app: &MockedInjectiveApp) -> Uint128 {
+        let querier = app.wrap();
+        let querier = InjectiveQuerier::new(&querier);
+        querier.query_token_factory_denom_total_supply(&DENOM).unwrap().total_supply
+    }
+
+    fn balance(app: &MockedInjectiveApp, address: &str, denom: &str) -> u128 {
+        app.wrap().query_balance(address, denom).unwrap().amount.u128()
+    }
+
+    #[test]
+    fn create_denom_charges_the_creation_fee() {
+        let app = app_with_denom();
+        let querier = app.wrap();
+        let querier = InjectiveQuerier::new(&querier);
+
+        assert_eq!(querier.query_denoms_from_creator(&CREATOR).unwrap().denoms, vec![DENOM.to_string()]);
+        assert_eq!(
+            querier
+                .query_denom_authority_metadata(&CREATOR, &"coin")
+                .unwrap()
+                .authority_metadata
+                .admin,
+            CREATOR
+        );
+        assert_eq!(querier.query_token_factory_creation_fee().unwrap().fee, vec![coin(10, "inj")]);
+        assert_eq!(balance(&app, CREATOR, "inj"), 990);
+        assert_eq!(balance(&app, COMMUNITY_POOL_ADDRESS, "inj"), 10);
+    }
+
+    #[test]
+    fn only_the_admin_mints_and_burns() {
+        let mut app = app_with_denom();
+        let holder = Addr::unchecked(HOLDER);
+        app.execute(
+            Addr::unchecked(CREATOR),
+            create_mint_tokens_msg(Addr::unchecked(CREATOR), coin(100, DENOM), HOLDER.to_string()),
+        )
+        .unwrap();
+
+        let err = app
+            .execute(
+                holder.clone(),
+                create_mint_tokens_msg(holder.clone(), coin(100, DENOM), HOLDER.to_string()),
+            )
+            .unwrap_err();
+        assert!(err.root_cause().to_string().contains("is not the admin"), "{err:?}");
+        let err = app.execute(holder.clone(), create_burn_tokens_msg(holder, coin(100, DENOM))).unwrap_err();
+        assert!(err.root_cause().to_string().contains("is not the admin"), "{err:?}");
+
+        assert_eq!(balance(&app, HOLDER, DENOM), 100);
+    }
+
+    #[test]
+    fn changed_admin_takes_over_minting() {
+        let mut app = app_with_denom();
+        let creator = Addr::unchecked(CREATOR);
+        let holder = Addr::unchecked(HOLDER);
+        app.execute(
+            creator.clone(),
+            create_change_admin_msg(creator.clone(), DENOM.to_string(), HOLDER.to_string()),
+        )
+        .unwrap();
+
+        app.execute(creator.clone(), create_mint_tokens_msg(creator, coin(100, DENOM), HOLDER.to_string()))
+            .unwrap_err();
+        app.execute(holder.clone(), create_mint_tokens_msg(holder, coin(100, DENOM), HOLDER.to_string()))
+            .unwrap();
+
+        assert_eq!(balance(&app, HOLDER, DENOM), 100);
+    }
+
+    #[test]
+    fn total_supply_follows_bank_burns() {
+        let mut app = app_with_denom();
+        let creator = Addr::unchecked(CREATOR);
+        app.execute(
+            creator.clone(),
+            create_mint_tokens_msg(creator.clone(), coin(300, DENOM), CREATOR.to_string()),
+        )
+        .unwrap();
+        app.execute(
+            creator.clone(),
+            create_mint_tokens_msg(creator.clone(), coin(200, DENOM), HOLDER.to_string()),
+        )
+        .unwrap();
+        app.execute(creator.clone(), create_burn_tokens_msg(creator, coin(50, DENOM))).unwrap();
+        assert_eq!(total_supply(&app), Uint128::new(450));
+
+        // burning through the bank module bypasses the tokenfactory, the supply still drops like on chain
+        app.execute(
+            Addr::unchecked(HOLDER),
+            BankMsg::Burn {
+                amount: vec![coin(20, DENOM)],
+            }
+            .into(),
+        )
+        .unwrap();
+
+        assert_eq!(total_supply(&app), Uint128::new(430));
+        assert_eq!(balance(&app, CREATOR, DENOM) + balance(&app, HOLDER, DENOM), 430);
+    }
+}